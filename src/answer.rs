@@ -0,0 +1,117 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to a part of a puzzle.
+///
+/// Numbers convert with `.into()`, and are compared to expected answers by
+/// value, so `"042"` is as good as `"42"`. Puzzles whose answer is read off
+/// a picture can return it as `Art`, shown on lines of its own.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A multi-line rendering, such as letters drawn by lit pixels.
+    Art(String),
+}
+
+// The lines of a rendering, ignoring trailing whitespace and blank lines
+// around it.
+fn art_lines(art: &str) -> Vec<&str> {
+    art.trim_matches('\n').lines().map(str::trim_end).collect()
+}
+
+impl Answer {
+    /// Draws `rows` of pixels, lit ones as blocks.
+    pub fn pixels<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                let line: String = row
+                    .as_ref()
+                    .iter()
+                    .map(|lit| if *lit { crate::block_char() } else { ' ' })
+                    .collect();
+                line.trim_end().to_string()
+            })
+            .collect();
+        Answer::Art(lines.join("\n"))
+    }
+
+    /// Whether this is the `expected` answer. Integers are compared by
+    /// value, and surrounding whitespace is ignored, on each line for art.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Integer(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(text) => text.trim() == expected.trim(),
+            Answer::Art(art) => art_lines(art) == art_lines(expected),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+/// Reads back an answer from how it's displayed, so that one passed around
+/// as text still compares by value: integers written as `Display` writes
+/// them become `Integer`, anything else converts as a `&str`.
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i128>() {
+            Ok(n) if n.to_string() == s => Answer::Integer(n),
+            _ => Answer::from(s),
+        })
+    }
+}
+
+/// Text spanning several lines is taken to be art.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        match text.trim_end().contains('\n') {
+            true => Answer::Art(text),
+            false => Answer::Text(text),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::from(text.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
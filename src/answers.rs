@@ -0,0 +1,255 @@
+use crate::{check_status, Answer, ErrorWrapper, Input, PartResult, TestStatus, DEFAULT_INPUT};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Expected answers kept in data files rather than in each day's
+/// `expected()`. Each day has its own file, `day_05.txt`, with one
+/// `part: answer` line per part. Answers for inputs other than the default
+/// one follow a `[name]` header, and answers for one particular content of
+/// an input a `[name hash]` header, where `hash` is its `Input::hash`:
+///
+/// ```text
+/// 1: 1234
+/// 2: 5678
+///
+/// [example1]
+/// 1: 42
+///
+/// [default 5d41402abc4b2a76]
+/// 1: 1111
+/// ```
+///
+/// This lets people with different puzzle inputs share a project: once any
+/// answer of an input is tied to a hash, inputs with any other content are
+/// unrecognized and their answers unknown, rather than wrong.
+///
+/// Newlines and backslashes in answers are escaped as `\n` and `\\`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    // Keyed by (day, input name, input hash, part)
+    answers: BTreeMap<(usize, String, Option<String>, usize), String>,
+}
+
+/// What the answers files know about a part of an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordedAnswer<'a> {
+    Known(&'a str),
+    /// Answers are recorded for particular contents of the input, but not
+    /// for this part of this one.
+    UnknownInput,
+    Unrecorded,
+}
+
+/// The directory answer files are read from and recorded to when the
+/// project doesn't set one: `src/answers` beneath `$CARGO_MANIFEST_DIR`, so
+/// only when run through cargo.
+pub fn default_answers_dir() -> Option<PathBuf> {
+    let mut path = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR")?);
+    path.push("src");
+    path.push("answers");
+    Some(path)
+}
+
+/// The `src/answers` directory of the crate using it, fixed when that crate
+/// is built. For `AoCProject::with_answers_dir`.
+#[macro_export]
+macro_rules! answers_dir {
+    () => {
+        std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src/answers"))
+    };
+}
+
+fn answers_file(dir: &Path, day: usize) -> PathBuf {
+    dir.join(format!("day_{:02}.txt", day))
+}
+
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+impl Answers {
+    pub fn new() -> Self {
+        Answers::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// The answer recorded for `part` of the input called `input`,
+    /// regardless of its content.
+    pub fn get(&self, day: usize, part: usize, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, input.to_string(), None, part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: usize, part: usize, input: &str, answer: String) {
+        self.answers.insert((day, input.to_string(), None, part), answer);
+    }
+
+    /// Records `answer` for `part` of this content of `input`, replacing any
+    /// answer recorded regardless of content.
+    pub fn insert_for(&mut self, day: usize, part: usize, input: &Input, answer: String) {
+        self.answers.remove(&(day, input.name.to_string(), None, part));
+        self.answers
+            .insert((day, input.name.to_string(), Some(input.hash()), part), answer);
+    }
+
+    /// The answer for `part` of `input`. An answer recorded for its content
+    /// wins over one recorded for its name only, whatever name the content
+    /// was recorded under. Once an input has answers recorded for particular
+    /// contents, answers for its name only are ignored.
+    pub fn lookup(&self, day: usize, part: usize, input: &Input) -> RecordedAnswer<'_> {
+        let hash = input.hash();
+        let by_hash = self
+            .answers
+            .iter()
+            .find(|((d, _, h, p), _)| *d == day && *p == part && h.as_deref() == Some(hash.as_str()));
+        if let Some((_, answer)) = by_hash {
+            return RecordedAnswer::Known(answer);
+        }
+        let hashed = self
+            .answers
+            .keys()
+            .any(|(d, name, h, _)| *d == day && name == input.name && h.is_some());
+        match (hashed, self.get(day, part, input.name)) {
+            (true, _) => RecordedAnswer::UnknownInput,
+            (false, Some(answer)) => RecordedAnswer::Known(answer),
+            (false, None) => RecordedAnswer::Unrecorded,
+        }
+    }
+
+    /// Whether any answer is known for `day`.
+    pub fn has_day(&self, day: usize) -> bool {
+        self.answers.keys().any(|(d, _, _, _)| *d == day)
+    }
+
+    /// Adds the answers of `day` in the answers file format.
+    pub fn parse_day(&mut self, day: usize, contents: &str) -> Result<(), ErrorWrapper> {
+        let mut input = DEFAULT_INPUT.to_string();
+        let mut hash: Option<String> = None;
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let mut words = header.split_whitespace();
+                input = words.next().unwrap_or(DEFAULT_INPUT).to_string();
+                hash = words.next().map(str::to_string);
+                continue;
+            }
+            let (part, answer) = line
+                .split_once(':')
+                .and_then(|(p, a)| Some((p.trim().parse::<usize>().ok()?, a)))
+                .ok_or_else(|| {
+                    ErrorWrapper::ParseError(format!("Invalid answer for day {} on line {}: {}", day, i + 1, line))
+                })?;
+            self.answers
+                .insert((day, input.clone(), hash.clone(), part), unescape(answer.trim()));
+        }
+        Ok(())
+    }
+
+    /// The answers of `day` in the answers file format.
+    pub fn format_day(&self, day: usize) -> String {
+        let mut sections: Vec<(&str, Option<&str>)> = vec![];
+        for (d, input, hash, _) in self.answers.keys() {
+            let section = (input.as_str(), hash.as_deref());
+            if *d == day && !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        sections.sort_by_key(|(input, hash)| (*input != DEFAULT_INPUT || hash.is_some(), *input, *hash));
+
+        let mut out = String::new();
+        for (input, hash) in sections {
+            if input != DEFAULT_INPUT || hash.is_some() {
+                if !out.is_empty() {
+                    out.push('\n');
+                }
+                match hash {
+                    Some(hash) => out.push_str(&format!("[{} {}]\n", input, hash)),
+                    None => out.push_str(&format!("[{}]\n", input)),
+                }
+            }
+            let section = self
+                .answers
+                .iter()
+                .filter(|((d, i, h, _), _)| *d == day && i == input && h.as_deref() == hash);
+            for ((_, _, _, part), answer) in section {
+                out.push_str(&format!("{}: {}\n", part, escape(answer)));
+            }
+        }
+        out
+    }
+
+    /// Reads every `day_XX.txt` file in `dir`. A missing directory has no
+    /// answers.
+    pub fn load(dir: &Path) -> Result<Self, ErrorWrapper> {
+        let mut answers = Answers::new();
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(answers),
+            Err(e) => return Err(e.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|x| x != "txt") {
+                continue;
+            }
+            let day = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.strip_prefix("day_"))
+                .and_then(|d| d.parse::<usize>().ok());
+            if let Some(day) = day {
+                answers.parse_day(day, &std::fs::read_to_string(&path)?)?;
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Writes the answers file of `day` to `dir`, creating it if needed.
+    pub fn save_day(&self, dir: &Path, day: usize) -> Result<(), ErrorWrapper> {
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(answers_file(dir, day), self.format_day(day))?;
+        Ok(())
+    }
+
+    /// Checks `result`, produced from `input`, against its recorded answer.
+    /// A recorded answer takes precedence over the day's `expected()`, and
+    /// an unrecognized input has no expected answer at all.
+    pub fn check(&self, result: &mut PartResult, input: &Input) {
+        let expected = match self.lookup(result.day, result.part, input) {
+            RecordedAnswer::Known(expected) => Some(expected),
+            RecordedAnswer::UnknownInput => None,
+            RecordedAnswer::Unrecorded => return,
+        };
+        result.expected = expected.map(str::to_string);
+        if let (Some(answer), TestStatus::Unknown | TestStatus::Success | TestStatus::Failure) =
+            (&result.answer, result.status)
+        {
+            let Ok(answer) = answer.parse::<Answer>();
+            result.status = check_status(expected, Ok(answer)).0;
+        }
+    }
+}
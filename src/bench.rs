@@ -0,0 +1,149 @@
+use crate::{format_duration, time, AoCDay, ErrorWrapper};
+use std::time::{Duration, Instant};
+
+/// How long a benchmark runs for once the warmup phase is over.
+#[derive(Clone, Copy, Debug)]
+pub enum BenchLimit {
+    Iterations(usize),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub limit: BenchLimit,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            limit: BenchLimit::Iterations(100),
+        }
+    }
+}
+
+/// Summary statistics over the samples of a benchmark, computed after
+/// discarding outliers outside of Tukey's fences (1.5 × IQR).
+#[derive(Clone, Debug)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub outliers: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl BenchStats {
+    /// Returns `None` if there are no samples to summarize.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<Duration> = samples.to_vec();
+        sorted.sort();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let iqr = q3.saturating_sub(q1);
+        let fence = iqr + iqr / 2;
+        let low = q1.saturating_sub(fence);
+        let high = q3 + fence;
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|d| *d >= low && *d <= high)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = kept
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(BenchStats {
+            samples: kept.len(),
+            outliers: sorted.len() - kept.len(),
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            median: percentile(&kept, 50.0),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95: percentile(&kept, 95.0),
+        })
+    }
+}
+
+impl std::fmt::Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {} / median {} / mean {} ± {} / p95 {} / max {} ({} samples, {} outliers)",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev),
+            format_duration(self.p95),
+            format_duration(self.max),
+            self.samples,
+            self.outliers,
+        )
+    }
+}
+
+// Nearest-rank percentile; `sorted` must be non-empty and sorted.
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    let rank = (pct / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Repeatedly runs one part of `day` against `input` and summarizes how long
+/// it took. The first error returned by the part aborts the benchmark. Days
+/// which parse their input separately parse it once up front, and part 2 of
+/// a `StatefulDay` is handed part 1's state before each run, so that only
+/// the part itself is timed.
+#[allow(clippy::borrowed_box)]
+pub fn bench_part(
+    day: &Box<dyn AoCDay>,
+    part: usize,
+    input: &str,
+    options: &BenchOptions,
+) -> Result<BenchStats, ErrorWrapper> {
+    let mut prepared = day.prepare(input).transpose()?;
+    // Times one run of the part, leaving out any setup it needs
+    let mut run = || -> Result<Duration, ErrorWrapper> {
+        let (result, elapsed) = match prepared.as_mut() {
+            Some(prepared) => {
+                prepared.setup(part)?;
+                time(|| prepared.part(part))
+            }
+            None => time(|| day.part(part, input)),
+        };
+        result.map(|_| elapsed)
+    };
+
+    for _ in 0..options.warmup {
+        run()?;
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    loop {
+        let done = match options.limit {
+            BenchLimit::Iterations(n) => samples.len() >= n,
+            BenchLimit::Time(budget) => !samples.is_empty() && start.elapsed() >= budget,
+        };
+        if done {
+            break;
+        }
+        samples.push(run()?);
+    }
+
+    BenchStats::from_samples(&samples)
+        .ok_or_else(|| ErrorWrapper::Simple("No samples collected".to_string()))
+}
@@ -31,6 +31,13 @@ pub fn get_inputs() -> HashMap<usize, &'static str> {{
     {}
     map
 }}
+
+#[allow(dead_code)]
+pub fn get_named_inputs() -> Vec<aoc_core::NamedInput> {{
+    vec![
+        {}
+    ]
+}}
 "#
     };
 }
@@ -111,18 +118,37 @@ pub fn generate_get_inputs() {
     // with_file_name replaces the last item on the PathBuf. I need to do this
     // in order to prevent it from clobbering /input
     buf.push("_");
-    let returns: String = inputs
+    // day_05.txt is the default input of day 5, day_05.example1.txt its
+    // example1 input
+    let parsed: Vec<(usize, &str, String)> = inputs
         .iter()
-        .map(|d|
-            format!("map.insert({}, include_str!(\"{}\"));\n",
-            d[4..].parse::<usize>().expect("Invalid usize").to_string(),
-            buf.with_file_name(d)
-                .with_extension("txt")
-                .to_str()
-                .expect("Error generating get_inputs"),
-        ))
+        .map(|d| {
+            let (day, name) = d[4..].split_once('.').unwrap_or((&d[4..], crate::DEFAULT_INPUT));
+            (
+                day.parse::<usize>().expect("Invalid usize"),
+                name,
+                buf.with_file_name(format!("{}.txt", d))
+                    .to_str()
+                    .expect("Error generating get_inputs")
+                    .to_string(),
+            )
+        })
+        .collect();
+    let returns: String = parsed
+        .iter()
+        .filter(|(_, name, _)| *name == crate::DEFAULT_INPUT)
+        .map(|(day, _, path)| format!("map.insert({}, include_str!({:?}));\n", day, path))
+        .collect();
+    let named: String = parsed
+        .iter()
+        .map(|(day, name, path)| {
+            format!(
+                "aoc_core::NamedInput {{ day: {}, name: {:?}, content: include_str!({:?}) }},\n",
+                day, name, path
+            )
+        })
         .collect();
 
-    f.write_all(format!(get_inputs_template!(), returns).as_bytes())
+    f.write_all(format!(get_inputs_template!(), returns, named).as_bytes())
         .unwrap();
 }
@@ -1,8 +1,277 @@
-pub trait AoCDay {
+use crate::{Answer, ErrorWrapper};
+
+pub trait AoCDay: Send + Sync {
     fn day(&self) -> usize;
-    fn part1(&self, input: &str) -> Result<String, crate::ErrorWrapper>;
-    fn part2(&self, input: &str) -> Result<String, crate::ErrorWrapper>;
+    fn part1(&self, input: &str) -> Result<Answer, crate::ErrorWrapper>;
+    /// Days with a single part, such as day 25, leave this out and set
+    /// `parts` to 1.
+    fn part2(&self, _input: &str) -> Result<Answer, crate::ErrorWrapper> {
+        Err(ErrorWrapper::NotImplemented)
+    }
+    /// How many parts the day has. Days can have parts beyond the second,
+    /// such as visualizations, by overriding `part` to run them.
+    fn parts(&self) -> usize {
+        2
+    }
+    /// Runs `part` of the day. Parts 1 and 2 run `part1` and `part2`.
+    fn part(&self, part: usize, input: &str) -> Result<Answer, ErrorWrapper> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => Err(ErrorWrapper::Simple(format!("Invalid part {}", part))),
+        }
+    }
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (None, None)
+    }
+    /// Expected answers for the input registered under `name`. Defaults to
+    /// `expected()` for the main input and to no answers for any other.
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        match name {
+            crate::DEFAULT_INPUT => self.expected(),
+            _ => (None, None),
+        }
+    }
+    /// How long the runner waits on each part before reporting it as timed
+    /// out. Overridden by `run --timeout`.
+    fn timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+    /// Prepares `input` once for every part run against it, for days
+    /// implemented as a `ParsedDay` or `StatefulDay`. `None` for days whose
+    /// parts take the input as is.
+    fn prepare<'a>(&'a self, _input: &'a str) -> Option<Result<Box<dyn PreparedInput + 'a>, ErrorWrapper>> {
+        None
+    }
+}
+
+/// An input prepared by `AoCDay::prepare`, ready to run parts against.
+pub trait PreparedInput {
+    fn part(&mut self, part: usize) -> Result<Answer, ErrorWrapper>;
+    /// Does whatever running `part` next depends on, so that it can be timed
+    /// on its own, such as running the part it carries on from. Nothing by
+    /// default.
+    fn setup(&mut self, _part: usize) -> Result<(), ErrorWrapper> {
+        Ok(())
+    }
+    /// Whether preparing the input parsed it, making the time it took worth
+    /// reporting.
+    fn parsed(&self) -> bool {
+        true
+    }
+    /// Whether the last run of `part` carried on from state left by an
+    /// earlier part rather than starting from scratch.
+    fn continued(&self, _part: usize) -> bool {
+        false
+    }
+}
+
+/// A day which parses its input in a step of its own. The runner parses
+/// each input once, passes the result to every part and reports the time
+/// taken to parse it separately from theirs.
+///
+/// Every `ParsedDay` is an `AoCDay`, so it can be boxed and registered like
+/// any other day.
+pub trait ParsedDay: Send + Sync {
+    type Parsed;
+
+    fn day(&self) -> usize;
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ErrorWrapper>;
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, ErrorWrapper>;
+    /// See `AoCDay::part2`.
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, ErrorWrapper> {
+        Err(ErrorWrapper::NotImplemented)
+    }
+    /// See `AoCDay::parts`.
+    fn parts(&self) -> usize {
+        2
+    }
+    /// See `AoCDay::part`.
+    fn part(&self, part: usize, parsed: &Self::Parsed) -> Result<Answer, ErrorWrapper> {
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => Err(ErrorWrapper::Simple(format!("Invalid part {}", part))),
+        }
+    }
+    /// See `AoCDay::expected`.
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (None, None)
+    }
+    /// See `AoCDay::expected_for`.
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        match name {
+            crate::DEFAULT_INPUT => self.expected(),
+            _ => (None, None),
+        }
+    }
+    /// See `AoCDay::timeout`.
+    fn timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+struct Parsed<'a, D: ParsedDay> {
+    day: &'a D,
+    parsed: D::Parsed,
+}
+
+impl<D: ParsedDay> PreparedInput for Parsed<'_, D> {
+    fn part(&mut self, part: usize) -> Result<Answer, ErrorWrapper> {
+        self.day.part(part, &self.parsed)
+    }
+}
+
+impl<D: ParsedDay> AoCDay for D {
+    fn day(&self) -> usize {
+        ParsedDay::day(self)
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        ParsedDay::part1(self, &self.parse(input)?)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        ParsedDay::part2(self, &self.parse(input)?)
+    }
+
+    fn parts(&self) -> usize {
+        ParsedDay::parts(self)
+    }
+
+    fn part(&self, part: usize, input: &str) -> Result<Answer, ErrorWrapper> {
+        ParsedDay::part(self, part, &self.parse(input)?)
+    }
+
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        ParsedDay::expected(self)
+    }
+
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        ParsedDay::expected_for(self, name)
+    }
+
+    fn timeout(&self) -> Option<std::time::Duration> {
+        ParsedDay::timeout(self)
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> Option<Result<Box<dyn PreparedInput + 'a>, ErrorWrapper>> {
+        Some(self.parse(input).map(|parsed| Box::new(Parsed { day: self, parsed }) as Box<dyn PreparedInput>))
+    }
+}
+
+/// A day whose part 2 carries on from where part 1 left off, for puzzles
+/// where part 2 extends something expensive part 1 works out. Part 1 returns
+/// its answer along with the state part 2 needs, so a day which finds both
+/// answers at once can return the second one as its state.
+///
+/// Wrap it in `Stateful` to register it as an `AoCDay`. When both parts are
+/// run together, part 2 is timed from where part 1 stopped. Run on its own,
+/// including when parts run as separate jobs (with `--split-parts`,
+/// `--timeout` or `--isolate`), it runs part 1 first and its time includes
+/// it. `bench` runs part 1 before each run of part 2 without timing it.
+pub trait StatefulDay: Send + Sync {
+    type State;
+
+    fn day(&self) -> usize;
+    fn part1(&self, input: &str) -> Result<(Answer, Self::State), ErrorWrapper>;
+    fn part2(&self, input: &str, state: Self::State) -> Result<Answer, ErrorWrapper>;
+    /// See `AoCDay::expected`.
     fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
         (None, None)
     }
+    /// See `AoCDay::expected_for`.
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        match name {
+            crate::DEFAULT_INPUT => self.expected(),
+            _ => (None, None),
+        }
+    }
+    /// See `AoCDay::timeout`.
+    fn timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+/// Adapts a `StatefulDay` to `AoCDay`.
+pub struct Stateful<D: StatefulDay>(pub D);
+
+struct Continued<'a, D: StatefulDay> {
+    day: &'a D,
+    input: &'a str,
+    // Left by part 1 until part 2 takes it
+    state: Option<D::State>,
+    continued: bool,
+}
+
+impl<D: StatefulDay> PreparedInput for Continued<'_, D> {
+    fn part(&mut self, part: usize) -> Result<Answer, ErrorWrapper> {
+        match part {
+            1 => {
+                let (answer, state) = self.day.part1(self.input)?;
+                self.state = Some(state);
+                Ok(answer)
+            }
+            2 => {
+                self.continued = self.state.is_some();
+                let state = match self.state.take() {
+                    Some(state) => state,
+                    None => self.day.part1(self.input)?.1,
+                };
+                self.day.part2(self.input, state)
+            }
+            _ => Err(ErrorWrapper::Simple(format!("Invalid part {}", part))),
+        }
+    }
+
+    fn setup(&mut self, part: usize) -> Result<(), ErrorWrapper> {
+        if part == 2 && self.state.is_none() {
+            self.state = Some(self.day.part1(self.input)?.1);
+        }
+        Ok(())
+    }
+
+    fn parsed(&self) -> bool {
+        false
+    }
+
+    fn continued(&self, part: usize) -> bool {
+        part == 2 && self.continued
+    }
+}
+
+impl<D: StatefulDay> AoCDay for Stateful<D> {
+    fn day(&self) -> usize {
+        self.0.day()
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(self.0.part1(input)?.0)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        let (_, state) = self.0.part1(input)?;
+        self.0.part2(input, state)
+    }
+
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        self.0.expected()
+    }
+
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        self.0.expected_for(name)
+    }
+
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.0.timeout()
+    }
+
+    fn prepare<'a>(&'a self, input: &'a str) -> Option<Result<Box<dyn PreparedInput + 'a>, ErrorWrapper>> {
+        Some(Ok(Box::new(Continued {
+            day: &self.0,
+            input,
+            state: None,
+            continued: false,
+        })))
+    }
 }
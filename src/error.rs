@@ -69,6 +69,11 @@ impl From<std::num::ParseFloatError> for ErrorWrapper {
         ErrorWrapper::ParseError(e.to_string())
     }
 }
+impl From<Box<dyn error::Error + Send + Sync>> for ErrorWrapper {
+    fn from(e: Box<dyn error::Error + Send + Sync>) -> Self {
+        ErrorWrapper::Wrapped(e)
+    }
+}
 impl From<Box<dyn error::Error>> for ErrorWrapper {
     fn from(e: Box<dyn error::Error>) -> Self {
         ErrorWrapper::Wrapped(e)
@@ -0,0 +1,63 @@
+use crate::{RunReport, TestStatus};
+
+/// The outcome of a CLI invocation, ordered from best to worst so that the
+/// overall status of a run is the maximum of its parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    /// Every answer matched or had nothing to compare against.
+    Passed,
+    /// An input or implementation for a requested day was not found.
+    Missing,
+    /// At least one answer didn't match its expected value.
+    WrongAnswer,
+    /// At least one part returned an error, panicked or timed out.
+    Errored,
+    /// The command line arguments were invalid.
+    Usage,
+}
+
+impl ExitStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            ExitStatus::Passed => 0,
+            ExitStatus::WrongAnswer => 1,
+            ExitStatus::Errored => 2,
+            ExitStatus::Missing => 3,
+            ExitStatus::Usage => 64,
+        }
+    }
+
+    pub fn from_report(report: &RunReport) -> Self {
+        report
+            .results
+            .iter()
+            .map(|r| match r.status {
+                TestStatus::Success | TestStatus::Unknown => ExitStatus::Passed,
+                TestStatus::Failure if r.error.is_none() => ExitStatus::WrongAnswer,
+                TestStatus::Rejected => ExitStatus::WrongAnswer,
+                TestStatus::Failure | TestStatus::Panicked | TestStatus::TimedOut => ExitStatus::Errored,
+            })
+            .max()
+            .unwrap_or(ExitStatus::Passed)
+    }
+
+    /// Like `from_report`, but only considers parts with a known answer, or
+    /// whose answer was rejected.
+    pub fn from_verified(report: &RunReport) -> Self {
+        let verified = RunReport {
+            results: report
+                .results
+                .iter()
+                .filter(|r| r.expected.is_some() || r.status == TestStatus::Rejected)
+                .cloned()
+                .collect(),
+        };
+        ExitStatus::from_report(&verified)
+    }
+}
+
+impl From<ExitStatus> for std::process::ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        std::process::ExitCode::from(status.code() as u8)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::answers::{escape, unescape};
+use crate::{ErrorWrapper, Input, PartResult, TestStatus};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ErrorWrapper;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(ErrorWrapper::ParseError(format!("Unknown verdict: {}", s))),
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// An answer submitted for a part of an input, identified by its
+/// `Input::hash`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub day: usize,
+    pub part: usize,
+    pub input: String,
+    pub hash: String,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// The history of submitted answers, kept in `guesses.txt` next to the
+/// answer files with one guess per line:
+///
+/// ```text
+/// 5 1 default 1fe4d1399f86710d too-high 1234
+/// ```
+///
+/// Numeric guesses which were too high or too low bound the answer, so a
+/// freshly computed answer can be rejected before it's submitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+}
+
+/// The file the guess log in `dir` is kept in.
+pub fn guesses_file(dir: &Path) -> PathBuf {
+    dir.join("guesses.txt")
+}
+
+impl GuessLog {
+    pub fn new() -> Self {
+        GuessLog::default()
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ErrorWrapper> {
+        let mut log = GuessLog::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            log.guesses.push(parse_guess(line).ok_or_else(|| {
+                ErrorWrapper::ParseError(format!("Invalid guess on line {}: {}", i + 1, line))
+            })?);
+        }
+        Ok(log)
+    }
+
+    /// Reads the guess log in `dir`. A missing log has no guesses.
+    pub fn load(dir: &Path) -> Result<Self, ErrorWrapper> {
+        match std::fs::read_to_string(guesses_file(dir)) {
+            Ok(contents) => GuessLog::parse(&contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(GuessLog::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Adds a guess at `part` of `input` to the log and appends it to the
+    /// log file in `dir`.
+    pub fn record(
+        &mut self,
+        dir: &Path,
+        day: usize,
+        part: usize,
+        input: &Input,
+        verdict: Verdict,
+        answer: &str,
+    ) -> Result<(), ErrorWrapper> {
+        let guess = Guess {
+            day,
+            part,
+            input: input.name.to_string(),
+            hash: input.hash(),
+            verdict,
+            answer: answer.to_string(),
+        };
+        std::fs::create_dir_all(dir)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(guesses_file(dir))?;
+        writeln!(file, "{}", format_guess(&guess))?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Guesses at `part` of `input`, oldest first.
+    pub fn for_part<'a>(&'a self, day: usize, part: usize, input: &Input) -> impl Iterator<Item = &'a Guess> {
+        let hash = input.hash();
+        self.guesses
+            .iter()
+            .filter(move |g| g.day == day && g.part == part && g.hash == hash)
+    }
+
+    /// Why `answer` to `part` of `input` can't be right according to the
+    /// earlier guesses, if it can't.
+    pub fn rejection(&self, day: usize, part: usize, input: &Input, answer: &str) -> Option<String> {
+        let guesses: Vec<&Guess> = self.for_part(day, part, input).collect();
+        if let Some(g) = guesses.iter().find(|g| g.answer == answer && g.verdict != Verdict::Correct) {
+            return Some(format!("already guessed, {}", g.verdict));
+        }
+        let value = answer.trim().parse::<i128>().ok()?;
+        let bound = |verdict: Verdict| {
+            guesses
+                .iter()
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.trim().parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| value >= *high) {
+            return Some(format!("must be lower than {}", high));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|low| value <= *low) {
+            return Some(format!("must be higher than {}", low));
+        }
+        None
+    }
+
+    /// Checks a `result` produced from `input` with no known answer against
+    /// the guesses at it. A correct guess is taken as the expected answer,
+    /// and an answer ruled out by the other guesses is rejected.
+    pub fn check(&self, result: &mut PartResult, input: &Input) {
+        let answer = match (&result.answer, result.status) {
+            (Some(answer), TestStatus::Unknown) => answer.clone(),
+            _ => return,
+        };
+        let correct = self
+            .for_part(result.day, result.part, input)
+            .find(|g| g.verdict == Verdict::Correct);
+        if let Some(correct) = correct {
+            result.status = match correct.answer == answer {
+                true => TestStatus::Success,
+                false => TestStatus::Failure,
+            };
+            result.expected = Some(correct.answer.clone());
+        } else if let Some(reason) = self.rejection(result.day, result.part, input, &answer) {
+            result.status = TestStatus::Rejected;
+            result.error = Some(reason);
+        }
+    }
+}
+
+fn parse_guess(line: &str) -> Option<Guess> {
+    let mut fields = line.splitn(6, ' ');
+    Some(Guess {
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        input: fields.next()?.to_string(),
+        hash: fields.next()?.to_string(),
+        verdict: fields.next()?.parse().ok()?,
+        answer: unescape(fields.next()?),
+    })
+}
+
+fn format_guess(guess: &Guess) -> String {
+    format!(
+        "{} {} {} {} {} {}",
+        guess.day,
+        guess.part,
+        guess.input,
+        guess.hash,
+        guess.verdict.name(),
+        escape(&guess.answer)
+    )
+}
@@ -1,8 +1,257 @@
-fn get_input(year: usize, day: usize) -> Result<String, ()> {
-    let mut dir = std::env::home_dir()?;
-    dir.push(".cache");
-    dir.push("aoc");
-    dir.push(year.to_string());
-    dir.push(day.to_string());
-    fs::create_dir_all(&dir)?;
+use crate::{ErrorWrapper, InputSource, DEFAULT_INPUT};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Where the puzzle site is, unless overridden by `$AOC_BASE_URL`.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the puzzle site on behalf of a user, identified by the session
+/// cookie of a logged in browser.
+///
+/// `from_env` reads the session token from `$AOC_SESSION`, or else from the
+/// `aoc/session` file in the config directory (`$XDG_CONFIG_HOME` or
+/// `~/.config`). Downloaded inputs are cached in `aoc/<year>/<day>/` under
+/// `$AOC_CACHE_DIR` or the cache directory (`$XDG_CACHE_HOME` or
+/// `~/.cache`), so each is only ever downloaded once.
+#[derive(Clone, Debug)]
+pub struct AoCClient {
+    pub year: usize,
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    /// Sent with every request, so the site's maintainers can get in touch
+    /// about a misbehaving tool.
+    pub user_agent: String,
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|h| !h.is_empty())
+        .map(PathBuf::from)
+}
+
+// `$var`, or `~/fallback` if it isn't set.
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|h| h.join(fallback)))
+}
+
+/// The session token from `$AOC_SESSION` or the `aoc/session` config file.
+pub fn session_token() -> Option<String> {
+    let token = std::env::var("AOC_SESSION").ok().or_else(|| {
+        let path = xdg_dir("XDG_CONFIG_HOME", ".config")?.join("aoc").join("session");
+        std::fs::read_to_string(path).ok()
+    })?;
+    let token = token.trim();
+    // Accept the token as copied from a cookie header too
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    match token.is_empty() {
+        true => None,
+        false => Some(token.to_string()),
+    }
+}
+
+/// When `day` of `year` unlocks: midnight US Eastern time (UTC-5) on that
+/// day of December.
+pub fn unlock_time(year: usize, day: usize) -> SystemTime {
+    // Days since the epoch of December `day`, from Howard Hinnant's
+    // days_from_civil
+    let (y, m, d) = (year as i64, 12i64, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    let secs = days * 86400 + 5 * 3600;
+    UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64)
+}
+
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    match (secs / 86400, secs / 3600 % 24, secs / 60 % 60) {
+        (0, 0, 0) => format!("{}s", secs.max(1)),
+        (0, 0, m) => format!("{}m {}s", m, secs % 60),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+impl AoCClient {
+    pub fn new(year: usize, user_agent: String) -> Self {
+        AoCClient {
+            year,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            cache_dir: None,
+            user_agent,
+        }
+    }
+
+    /// A client configured from the environment and config files.
+    pub fn from_env(year: usize, user_agent: String) -> Self {
+        AoCClient {
+            base_url: std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: session_token(),
+            cache_dir: std::env::var_os("AOC_CACHE_DIR")
+                .filter(|d| !d.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| xdg_dir("XDG_CACHE_HOME", ".cache").map(|d| d.join("aoc"))),
+            ..AoCClient::new(year, user_agent)
+        }
+    }
+
+    /// Where the input of `day` is cached.
+    pub fn cache_path(&self, day: usize) -> Option<PathBuf> {
+        let mut path = self.cache_dir.clone()?;
+        path.push(self.year.to_string());
+        path.push(day.to_string());
+        path.push("input.txt");
+        Some(path)
+    }
+
+    pub fn cached_input(&self, day: usize) -> Option<String> {
+        std::fs::read_to_string(self.cache_path(day)?).ok()
+    }
+
+    /// Fails with how long is left until `day` unlocks, if it hasn't yet.
+    pub fn ensure_unlocked(&self, day: usize) -> Result<(), ErrorWrapper> {
+        if !(1..=25).contains(&day) {
+            return Err(ErrorWrapper::Simple(format!("There is no day {}", day)));
+        }
+        match unlock_time(self.year, day).duration_since(SystemTime::now()) {
+            Ok(wait) => Err(ErrorWrapper::Simple(format!(
+                "Day {} unlocks in {}",
+                day,
+                format_wait(wait)
+            ))),
+            Err(_) => Ok(()),
+        }
+    }
+
+    pub(crate) fn session(&self) -> Result<&str, ErrorWrapper> {
+        self.session.as_deref().ok_or_else(|| {
+            ErrorWrapper::Simple(
+                "No session token, set $AOC_SESSION or write it to ~/.config/aoc/session".to_string(),
+            )
+        })
+    }
+
+    /// Sends a request for `path` on the site, logged in if there is a
+    /// session token and with `form` as a POST body if given, returning the
+    /// status code and body.
+    #[cfg(feature = "net")]
+    pub fn request(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<(u16, String), ErrorWrapper> {
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&self.user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+        let mut request = match form {
+            Some(_) => agent.post(&url),
+            None => agent.get(&url),
+        };
+        if let Some(session) = self.session.as_deref() {
+            request = request.set("Cookie", &format!("session={}", session));
+        }
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(ErrorWrapper::Simple(e.to_string())),
+        };
+        let status = response.status();
+        Ok((status, response.into_string()?))
+    }
+
+    #[cfg(not(feature = "net"))]
+    pub fn request(&self, _path: &str, _form: Option<&[(&str, &str)]>) -> Result<(u16, String), ErrorWrapper> {
+        Err(ErrorWrapper::Simple("Built without the net feature".to_string()))
+    }
+
+    /// Downloads the input of `day` and caches it, even if it was cached
+    /// already.
+    pub fn download_input(&self, day: usize) -> Result<String, ErrorWrapper> {
+        self.session()?;
+        self.ensure_unlocked(day)?;
+        let (status, body) = self.request(&format!("/{}/day/{}/input", self.year, day), None)?;
+        match status {
+            200 => {}
+            400 | 500 if body.contains("log in") => {
+                return Err(ErrorWrapper::Simple("The session token was rejected, it may have expired".to_string()))
+            }
+            404 => return Err(ErrorWrapper::Simple(format!("No input found for day {} of {}", day, self.year))),
+            _ => return Err(ErrorWrapper::Simple(format!("Downloading input failed with status {}: {}", status, body.trim()))),
+        }
+        if let Some(path) = self.cache_path(day) {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, &body)?;
+        }
+        Ok(body)
+    }
+
+    /// The input of `day`, from the cache if it has been downloaded before.
+    pub fn get_input(&self, day: usize) -> Result<String, ErrorWrapper> {
+        match self.cached_input(day) {
+            Some(input) => Ok(input),
+            None => self.download_input(day),
+        }
+    }
+}
+
+/// The input of `day` of `year`, downloaded with the session token from the
+/// environment unless cached.
+pub fn get_input(year: usize, day: usize) -> Result<String, ErrorWrapper> {
+    let user_agent = format!("aoc_core/{}", env!("CARGO_PKG_VERSION"));
+    AoCClient::from_env(year, user_agent).get_input(day)
+}
+
+/// Looks up inputs in `inputs` first, falling back to the cache and then to
+/// downloading the main input of days without one, even if they have
+/// examples. Downloads are only tried with a session token.
+pub struct WithDownloads<'a, I: InputSource + ?Sized> {
+    pub inputs: &'a I,
+    pub client: AoCClient,
+}
+
+impl<I: InputSource + ?Sized> InputSource for WithDownloads<'_, I> {
+    fn get(&self, day: usize, name: &str) -> Option<String> {
+        if let Some(input) = self.inputs.get(day, name) {
+            return Some(input);
+        }
+        // Only the main input can be downloaded, and it wasn't embedded
+        if name != DEFAULT_INPUT {
+            return None;
+        }
+        if let Some(input) = self.client.cached_input(day) {
+            return Some(input);
+        }
+        self.client.session.as_ref()?;
+        match self.client.download_input(day) {
+            Ok(input) => Some(input),
+            Err(e) => {
+                eprintln!("Unable to download input for day {}: {}", day, e);
+                None
+            }
+        }
+    }
+
+    fn names(&self, day: usize) -> Vec<String> {
+        let mut names = self.inputs.names(day);
+        if !names.iter().any(|n| n == DEFAULT_INPUT) {
+            let available = self.client.cached_input(day).is_some()
+                || (self.client.session.is_some() && self.client.ensure_unlocked(day).is_ok());
+            if available {
+                names.insert(0, DEFAULT_INPUT.to_string());
+            }
+        }
+        names
+    }
 }
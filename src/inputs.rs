@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+/// The name given to a day's main puzzle input, `day_XX.txt`.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// A puzzle input, along with the name it was registered under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Input<'a> {
+    pub name: &'a str,
+    pub content: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn named(name: &'a str, content: &'a str) -> Self {
+        Input { name, content }
+    }
+
+    /// A short hash identifying the content of the input, ignoring line
+    /// endings and trailing whitespace, so answers can be tied to the input
+    /// they were computed from.
+    pub fn hash(&self) -> String {
+        // 64-bit FNV-1a, which unlike `DefaultHasher` is stable across builds
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let lines = self.content.trim_end().lines().map(|l| l.trim_end_matches('\r'));
+        for (i, line) in lines.enumerate() {
+            let separator: &[u8] = if i == 0 { b"" } else { b"\n" };
+            for b in separator.iter().chain(line.as_bytes()) {
+                hash ^= *b as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        }
+        format!("{:016x}", hash)
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(content: &'a str) -> Self {
+        Input::named(DEFAULT_INPUT, content)
+    }
+}
+
+impl<'a> From<&'a String> for Input<'a> {
+    fn from(content: &'a String) -> Self {
+        Input::from(content.as_str())
+    }
+}
+
+/// An input embedded by `generate_get_inputs`. `day_05.txt` is registered as
+/// the `DEFAULT_INPUT` of day 5 and `day_05.example1.txt` as its `example1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub day: usize,
+    pub name: &'static str,
+    pub content: &'static str,
+}
+
+/// Where `AoCProject::run` looks up the inputs for each day.
+pub trait InputSource {
+    fn get(&self, day: usize, name: &str) -> Option<String>;
+    /// Names of the inputs available for `day`, with `DEFAULT_INPUT` first.
+    fn names(&self, day: usize) -> Vec<String>;
+}
+
+impl<S> InputSource for HashMap<usize, S>
+where
+    S: AsRef<str>,
+{
+    fn get(&self, day: usize, name: &str) -> Option<String> {
+        match name {
+            DEFAULT_INPUT => HashMap::get(self, &day).map(|s| s.as_ref().to_string()),
+            _ => None,
+        }
+    }
+
+    fn names(&self, day: usize) -> Vec<String> {
+        match self.contains_key(&day) {
+            true => vec![DEFAULT_INPUT.to_string()],
+            false => vec![],
+        }
+    }
+}
+
+impl InputSource for [NamedInput] {
+    fn get(&self, day: usize, name: &str) -> Option<String> {
+        self.iter()
+            .find(|i| i.day == day && i.name == name)
+            .map(|i| i.content.to_string())
+    }
+
+    fn names(&self, day: usize) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .iter()
+            .filter(|i| i.day == day)
+            .map(|i| i.name.to_string())
+            .collect();
+        names.sort_by_key(|n| (n != DEFAULT_INPUT, n.clone()));
+        names
+    }
+}
+
+impl InputSource for Vec<NamedInput> {
+    fn get(&self, day: usize, name: &str) -> Option<String> {
+        InputSource::get(self.as_slice(), day, name)
+    }
+
+    fn names(&self, day: usize) -> Vec<String> {
+        self.as_slice().names(day)
+    }
+}
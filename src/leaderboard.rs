@@ -0,0 +1,234 @@
+use crate::{unlock_time, AoCClient, ErrorWrapper};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A member of a private leaderboard and the stars they have collected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Unset for members who haven't linked a name to their account.
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: usize,
+    pub global_score: usize,
+    pub last_star_ts: u64,
+    /// When each star was collected, as Unix timestamps, by day and part.
+    pub completion: BTreeMap<usize, BTreeMap<usize, u64>>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star_ts(&self, day: usize, part: usize) -> Option<u64> {
+        self.completion.get(&day)?.get(&part).copied()
+    }
+
+    /// How long after part 1 part 2 of `day` was solved.
+    pub fn part_delta(&self, day: usize) -> Option<Duration> {
+        let (part1, part2) = (self.star_ts(day, 1)?, self.star_ts(day, 2)?);
+        Some(Duration::from_secs(part2.saturating_sub(part1)))
+    }
+}
+
+/// A private leaderboard, in the JSON format the puzzle site provides at
+/// `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+// Numbers show up as strings in some fields of older leaderboards.
+fn number(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str()?.parse().ok())
+}
+
+fn parse_member(value: &Value) -> Option<Member> {
+    let mut completion = BTreeMap::new();
+    if let Some(days) = value.get("completion_day_level").and_then(Value::as_object) {
+        for (day, parts) in days {
+            let mut stars = BTreeMap::new();
+            for (part, star) in parts.as_object()? {
+                stars.insert(part.parse().ok()?, number(star.get("get_star_ts")?)?);
+            }
+            completion.insert(day.parse().ok()?, stars);
+        }
+    }
+    Some(Member {
+        id: number(value.get("id")?)?,
+        name: value.get("name").and_then(Value::as_str).map(str::to_string),
+        stars: number(value.get("stars")?)? as usize,
+        local_score: number(value.get("local_score")?)? as usize,
+        global_score: value.get("global_score").and_then(number).unwrap_or(0) as usize,
+        last_star_ts: value.get("last_star_ts").and_then(number).unwrap_or(0),
+        completion,
+    })
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, ErrorWrapper> {
+        let invalid = || ErrorWrapper::ParseError("Invalid leaderboard".to_string());
+        let value: Value = serde_json::from_str(json).map_err(|e| ErrorWrapper::ParseError(e.to_string()))?;
+        let members = value
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or_else(invalid)?
+            .values()
+            .map(|m| parse_member(m).ok_or_else(invalid))
+            .collect::<Result<Vec<Member>, ErrorWrapper>>()?;
+        Ok(Leaderboard {
+            owner_id: value.get("owner_id").and_then(number).unwrap_or(0),
+            event: value.get("event").and_then(Value::as_str).unwrap_or_default().to_string(),
+            members,
+        })
+    }
+
+    /// Members by local score, ties going to whoever got there first.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        members
+    }
+
+    fn year(&self) -> Option<usize> {
+        self.event.parse().ok()
+    }
+
+    /// How long after `day` unlocked `member` solved `part` of it.
+    pub fn solve_time(&self, member: &Member, day: usize, part: usize) -> Option<Duration> {
+        let unlock = unlock_time(self.year()?, day).duration_since(UNIX_EPOCH).ok()?;
+        let ts = member.star_ts(day, part)?;
+        Some(Duration::from_secs(ts.saturating_sub(unlock.as_secs())))
+    }
+}
+
+/// Formats a solve time as `h:mm:ss`, with whole days in front if needed.
+pub fn format_solve_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    let clock = format!("{}:{:02}:{:02}", secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match secs / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+/// Writes the members of `board` by rank, with their stars for each day:
+/// `*` for both parts, `.` for part 1 only.
+pub fn render_rankings<W: Write>(board: &Leaderboard, out: &mut W) -> std::io::Result<()> {
+    let days: usize = board
+        .members
+        .iter()
+        .flat_map(|m| m.completion.keys())
+        .max()
+        .copied()
+        .unwrap_or(0)
+        .max(1);
+    // Lines up with the rank, score and stars of each row
+    let header = "  #  Score  Stars  ";
+    let digit = |n: usize| char::from(b'0' + (n % 10) as u8);
+    if days >= 10 {
+        let tens: String = (1..=days).map(|d| if d >= 10 { digit(d / 10) } else { ' ' }).collect();
+        writeln!(out, "{:width$}{}", "", tens, width = header.len())?;
+    }
+    let ones: String = (1..=days).map(digit).collect();
+    writeln!(out, "{}{}  Name", header, ones)?;
+    for (i, member) in board.ranked().into_iter().enumerate() {
+        let stars: String = (1..=days)
+            .map(|d| match (member.star_ts(d, 1), member.star_ts(d, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '.',
+                _ => ' ',
+            })
+            .collect();
+        writeln!(
+            out,
+            "{:>3}  {:>5}  {:>5}  {}  {}",
+            i + 1,
+            member.local_score,
+            member.stars,
+            stars,
+            member.display_name()
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes how long after `day` unlocked each member solved each part, and
+/// the time between the parts, fastest first.
+pub fn render_day<W: Write>(board: &Leaderboard, day: usize, out: &mut W) -> std::io::Result<()> {
+    let mut members: Vec<&Member> = board
+        .members
+        .iter()
+        .filter(|m| m.star_ts(day, 1).is_some())
+        .collect();
+    members.sort_by_key(|m| {
+        (
+            m.star_ts(day, 2).is_none(),
+            m.star_ts(day, 2).or(m.star_ts(day, 1)),
+            m.id,
+        )
+    });
+    let time = |t: Option<Duration>| t.map_or("-".to_string(), format_solve_time);
+    writeln!(out, "Day {:02}", day)?;
+    writeln!(out, "  #        Part 1        Part 2         Delta  Name")?;
+    for (i, member) in members.into_iter().enumerate() {
+        writeln!(
+            out,
+            "{:>3}  {:>12}  {:>12}  {:>12}  {}",
+            i + 1,
+            time(board.solve_time(member, day, 1)),
+            time(board.solve_time(member, day, 2)),
+            time(member.part_delta(day)),
+            member.display_name()
+        )?;
+    }
+    Ok(())
+}
+
+impl AoCClient {
+    /// Downloads the private leaderboard `id`. A copy downloaded in the last
+    /// 15 minutes is used instead, as the puzzle site asks.
+    pub fn fetch_leaderboard(&self, id: u64) -> Result<Leaderboard, ErrorWrapper> {
+        let cached = self.cache_dir.as_ref().map(|dir| {
+            dir.join(self.year.to_string())
+                .join(format!("leaderboard_{}.json", id))
+        });
+        let fresh = cached.as_ref().and_then(|path| {
+            let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+            let age = SystemTime::now().duration_since(modified).unwrap_or_default();
+            match age < Duration::from_secs(15 * 60) {
+                true => std::fs::read_to_string(path).ok(),
+                false => None,
+            }
+        });
+        if let Some(json) = fresh {
+            return Leaderboard::parse(&json);
+        }
+
+        self.session()?;
+        let path = format!("/{}/leaderboard/private/view/{}.json", self.year, id);
+        let (status, body) = self.request(&path, None)?;
+        if status != 200 || !body.trim_start().starts_with('{') {
+            return Err(ErrorWrapper::Simple(format!(
+                "Unable to access leaderboard {} (status {}), check the id and session token",
+                id, status
+            )));
+        }
+        let board = Leaderboard::parse(&body)?;
+        if let Some(path) = cached {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, &body)?;
+        }
+        Ok(board)
+    }
+}
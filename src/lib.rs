@@ -1,11 +1,51 @@
-#![feature(pattern)]
-
 mod builder;
 pub use builder::{generate_get_days, generate_get_inputs};
 
 mod project;
 pub use project::{AoCProject, run_day};
 
+mod timing;
+pub use timing::{format_duration, time, DayTimings};
+
+mod bench;
+pub use bench::{bench_part, BenchLimit, BenchOptions, BenchStats};
+
+mod panic;
+pub use panic::{catch_panic, PanicDetails};
+
+mod report;
+pub use report::{check_status, expected_answer, run_part, solve_day, PartResult, RunReport, TestStatus};
+
+mod answer;
+pub use answer::Answer;
+
+mod answers;
+pub use answers::{default_answers_dir, Answers, RecordedAnswer};
+
+mod guesses;
+pub use guesses::{guesses_file, Guess, GuessLog, Verdict};
+
+mod selection;
+pub use selection::DaySelection;
+
+mod exit;
+pub use exit::ExitStatus;
+
+mod parallel;
+pub use parallel::{solve_days_parallel, RunOptions};
+
+mod watchdog;
+pub use watchdog::{run_part_isolated, timed_out};
+
+mod watch;
+pub use watch::{default_input_path, latest_modified, watch_day, WatchOptions};
+
+mod render;
+pub use render::{part_from_json, part_json, CsvRenderer, JsonRenderer, OutputFormat, Renderer, SummaryRenderer, TextRenderer};
+
+mod progress;
+pub use progress::{PartProgress, ProgressRenderer};
+
 mod parse;
 pub use parse::*;
 
@@ -21,7 +61,22 @@ mod error;
 pub use error::ErrorWrapper;
 
 mod day;
-pub use day::AoCDay;
+pub use day::{AoCDay, ParsedDay, PreparedInput, Stateful, StatefulDay};
+
+mod inputs;
+pub use inputs::{Input, InputSource, NamedInput, DEFAULT_INPUT};
+
+mod input;
+pub use input::{get_input, session_token, unlock_time, AoCClient, WithDownloads, DEFAULT_BASE_URL};
+
+mod puzzle;
+pub use puzzle::{decode_entities, extract_examples, puzzle_markdown, save_examples};
+
+mod leaderboard;
+pub use leaderboard::{format_solve_time, render_day, render_rankings, Leaderboard, Member};
+
+mod submit;
+pub use submit::{parse_submit_response, SubmitOutcome};
 
 // Useful for output
 pub fn block_char() -> char {
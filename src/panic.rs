@@ -0,0 +1,69 @@
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// The message and source location of a caught panic.
+#[derive(Clone, Debug)]
+pub struct PanicDetails {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl std::fmt::Display for PanicDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    // Set while `catch_panic` is running on this thread, so the hook knows to
+    // record the panic instead of printing it.
+    static CAUGHT: RefCell<Option<Option<PanicDetails>>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let captured = CAUGHT.with(|c| match c.borrow_mut().as_mut() {
+                Some(slot) => {
+                    let message = match info.payload().downcast_ref::<&str>() {
+                        Some(s) => s.to_string(),
+                        None => match info.payload().downcast_ref::<String>() {
+                            Some(s) => s.clone(),
+                            None => "Box<dyn Any>".to_string(),
+                        },
+                    };
+                    *slot = Some(PanicDetails {
+                        message,
+                        location: info.location().map(|l| l.to_string()),
+                    });
+                    true
+                }
+                None => false,
+            });
+            if !captured {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `func`, turning a panic into an error instead of unwinding further.
+pub fn catch_panic<T, F: FnOnce() -> T>(func: F) -> Result<T, PanicDetails> {
+    install_hook();
+    let outer = CAUGHT.with(|c| c.borrow_mut().replace(None));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    let details = CAUGHT.with(|c| std::mem::replace(&mut *c.borrow_mut(), outer));
+    result.map_err(|_| {
+        details.flatten().unwrap_or(PanicDetails {
+            message: "unknown panic".to_string(),
+            location: None,
+        })
+    })
+}
@@ -0,0 +1,175 @@
+use crate::{run_part_isolated, solve_day, timed_out, AoCDay, Answers, GuessLog, Input, Renderer, RunReport};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    /// Only run this part of each day.
+    pub part: Option<usize>,
+    /// Maximum number of jobs running at once.
+    pub jobs: usize,
+    /// Run each part of a day as a separate job. Always the case for days
+    /// with a timeout and for isolated runs.
+    pub split_parts: bool,
+    /// Per-part timeout, taking precedence over `AoCDay::timeout`.
+    pub timeout: Option<Duration>,
+    /// Run each part in a child process of this executable (see
+    /// `run_part_isolated`) so that timed out parts can be killed.
+    pub isolate: Option<PathBuf>,
+    /// Called with the report once every result has been rendered if timed
+    /// out parts are still running in-process. The run waits for them after
+    /// it returns, so a caller with nothing left to do can exit from it.
+    pub on_stuck: Option<fn(&RunReport)>,
+    /// Recorded answers each result is checked against.
+    pub answers: Answers,
+    /// Earlier guesses, which results with no known answer are checked
+    /// against.
+    pub guesses: GuessLog,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            part: None,
+            jobs: 1,
+            split_parts: false,
+            timeout: None,
+            isolate: None,
+            on_stuck: None,
+            answers: Answers::new(),
+            guesses: GuessLog::new(),
+        }
+    }
+}
+
+struct Task {
+    work: usize,
+    part: Option<usize>,
+    timeout: Option<Duration>,
+}
+
+/// Runs each `(day, input)` pair with up to `options.jobs` jobs in flight,
+/// each on its own thread. Results are passed to `renderer` a day at a time
+/// in the order of `work`, as soon as that day and every day before it have
+/// finished.
+///
+/// A part which exceeds its timeout is reported as timed out and no longer
+/// counts towards `jobs`, but an in-process part can't be stopped, so this
+/// only returns once it finishes. `options.on_stuck` is called before then.
+#[allow(clippy::borrowed_box)]
+pub fn solve_days_parallel(
+    work: &[(&Box<dyn AoCDay>, Input)],
+    options: &RunOptions,
+    renderer: &mut dyn Renderer,
+) -> RunReport {
+    let tasks: Vec<Task> = work
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (day, _))| {
+            let timeout = options.timeout.or_else(|| day.timeout());
+            let split = options.split_parts || timeout.is_some() || options.isolate.is_some();
+            match options.part {
+                Some(p) => vec![Some(p)],
+                None if split => (1..=day.parts()).map(Some).collect(),
+                None => vec![None],
+            }
+            .into_iter()
+            .map(move |part| Task { work: i, part, timeout })
+        })
+        .collect();
+    let (tx, rx) = mpsc::channel::<(usize, RunReport)>();
+
+    let mut report = RunReport::new();
+    std::thread::scope(|scope| {
+        let mut finished: Vec<Option<RunReport>> = vec![None; tasks.len()];
+        // Deadlines of the tasks currently being waited on
+        let mut running: HashMap<usize, Option<Instant>> = HashMap::new();
+        let mut spawned = 0;
+        let mut emitted = 0;
+        let mut stuck = 0;
+        let mut current = RunReport::new();
+
+        while emitted < tasks.len() {
+            while running.len() < options.jobs.max(1) && spawned < tasks.len() {
+                let t = spawned;
+                let task = &tasks[t];
+                let (day, input) = work[task.work];
+                let tx = tx.clone();
+                let isolate = options.isolate.as_deref();
+                scope.spawn(move || {
+                    let result = match (isolate, task.part) {
+                        (Some(exe), Some(part)) => RunReport {
+                            results: vec![run_part_isolated(exe, day, part, input, task.timeout)],
+                        },
+                        _ => solve_day(day, task.part, input),
+                    };
+                    let _ = tx.send((t, result));
+                });
+                // Isolated parts enforce their own timeout by killing the child
+                let deadline = match isolate {
+                    Some(_) => None,
+                    None => task.timeout.map(|d| Instant::now() + d),
+                };
+                running.insert(t, deadline);
+                spawned += 1;
+            }
+
+            let next_deadline = running.values().flatten().min().copied();
+            let received = match next_deadline {
+                Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok((t, mut result)) => {
+                    // Late results from parts already reported as timed out are dropped
+                    if running.remove(&t).is_some() {
+                        let input = work[tasks[t].work].1;
+                        for r in result.results.iter_mut() {
+                            options.answers.check(r, &input);
+                            options.guesses.check(r, &input);
+                        }
+                        finished[t] = Some(result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let now = Instant::now();
+                    let expired: Vec<usize> = running
+                        .iter()
+                        .filter(|(_, deadline)| deadline.is_some_and(|d| d <= now))
+                        .map(|(t, _)| *t)
+                        .collect();
+                    for t in expired {
+                        running.remove(&t);
+                        let task = &tasks[t];
+                        let (day, input) = work[task.work];
+                        let timeout = task.timeout.unwrap_or_default();
+                        let mut result = timed_out(day, task.part.unwrap_or(1), input.name, timeout);
+                        options.answers.check(&mut result, &input);
+                        finished[t] = Some(RunReport { results: vec![result] });
+                        stuck += 1;
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            // Results arrive in completion order; hold them until every task
+            // before them has finished so days are reported in order.
+            while let Some(result) = finished.get_mut(emitted).and_then(Option::take) {
+                current.extend(result);
+                emitted += 1;
+                if tasks.get(emitted).map(|t| t.work) != Some(tasks[emitted - 1].work) {
+                    renderer.day(&current.results);
+                    report.extend(std::mem::take(&mut current));
+                }
+            }
+        }
+        renderer.finish(&report);
+
+        if let Some(on_stuck) = options.on_stuck.filter(|_| stuck > 0) {
+            on_stuck(&report);
+        }
+    });
+    report
+}
@@ -0,0 +1,101 @@
+use crate::{PartResult, Renderer, RunReport, TestStatus, DEFAULT_INPUT};
+use std::io::Write;
+
+/// How far along a part of a day is, as shown by the `progress` calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartProgress {
+    NotImplemented,
+    NoInput,
+    Unverified,
+    Verified,
+    Failing,
+    /// The day has no such part, as with part 2 of day 25.
+    NoPart,
+}
+
+impl PartProgress {
+    pub fn from_result(result: &PartResult) -> Self {
+        match result.status {
+            TestStatus::Success => PartProgress::Verified,
+            TestStatus::Unknown => PartProgress::Unverified,
+            TestStatus::Failure if result.not_implemented => PartProgress::NotImplemented,
+            TestStatus::Failure | TestStatus::Panicked | TestStatus::TimedOut | TestStatus::Rejected => {
+                PartProgress::Failing
+            }
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            PartProgress::NotImplemented => '.',
+            PartProgress::NoInput => '-',
+            PartProgress::Unverified => '?',
+            PartProgress::Verified => '*',
+            PartProgress::Failing => 'x',
+            PartProgress::NoPart => ' ',
+        }
+    }
+}
+
+/// Shows the main input results of a run as a 25-day calendar once it's
+/// finished, with a symbol for each part of each day.
+pub struct ProgressRenderer<W: Write> {
+    out: W,
+    implemented: Vec<usize>,
+    missing_input: Vec<usize>,
+}
+
+impl<W: Write> ProgressRenderer<W> {
+    /// `implemented` are the days with a solution, and `missing_input` those
+    /// of them which weren't run for lack of an input.
+    pub fn new(out: W, implemented: Vec<usize>, missing_input: Vec<usize>) -> Self {
+        ProgressRenderer {
+            out,
+            implemented,
+            missing_input,
+        }
+    }
+
+    /// The progress of each part of `day` according to `report`.
+    pub fn day_progress(&self, report: &RunReport, day: usize) -> [PartProgress; 2] {
+        let part = |part: usize| {
+            if !self.implemented.contains(&day) {
+                return PartProgress::NotImplemented;
+            }
+            if self.missing_input.contains(&day) {
+                return PartProgress::NoInput;
+            }
+            report
+                .results
+                .iter()
+                .find(|r| r.day == day && r.part == part && r.input == DEFAULT_INPUT)
+                .map_or(PartProgress::NoPart, PartProgress::from_result)
+        };
+        [part(1), part(2)]
+    }
+}
+
+impl<W: Write> Renderer for ProgressRenderer<W> {
+    fn day(&mut self, _results: &[PartResult]) {}
+
+    fn finish(&mut self, report: &RunReport) {
+        let mut verified = 0;
+        for week in 0..5 {
+            let cells: Vec<String> = (1..=5)
+                .map(|d| week * 5 + d)
+                .map(|day| {
+                    let parts = self.day_progress(report, day);
+                    verified += parts.iter().filter(|p| **p == PartProgress::Verified).count();
+                    format!("{:>2} {}{}", day, parts[0].symbol(), parts[1].symbol())
+                })
+                .collect();
+            writeln!(self.out, "{}", cells.join("   ")).unwrap();
+        }
+        writeln!(
+            self.out,
+            "\n* verified  ? unverified  x failing  - no input  . not implemented"
+        )
+        .unwrap();
+        writeln!(self.out, "Verified: {}/50", verified).unwrap();
+    }
+}
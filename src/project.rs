@@ -1,6 +1,9 @@
-use crate::AoCDay;
-use std::collections::HashMap;
-use clap::{clap_app, AppSettings};
+use crate::{bench_part, default_answers_dir, default_input_path, extract_examples, puzzle_markdown, render_day, render_rankings, save_examples, solve_day, solve_days_parallel, watch_day, AoCClient, AoCDay, Answers, BenchLimit, BenchOptions, DaySelection, ErrorWrapper, ExitStatus, GuessLog, Input, InputSource, Leaderboard, OutputFormat, PartResult, ProgressRenderer, RecordedAnswer, Renderer, RunOptions, RunReport, SubmitOutcome, SummaryRenderer, TestStatus, TextRenderer, Verdict, WatchOptions, WithDownloads, DEFAULT_INPUT};
+use std::ffi::OsString;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use clap::{clap_app, AppSettings, ArgMatches, ErrorKind};
 
 pub struct AoCProject {
     pub year: usize,
@@ -8,6 +11,9 @@ pub struct AoCProject {
     pub title: String,
     pub author: String,
     pub url: Option<String>,
+    /// Where answers are read from and recorded to unless `--answers` says
+    /// otherwise. See `with_answers_dir`.
+    pub answers_dir: Option<PathBuf>,
 }
 
 impl AoCProject {
@@ -24,46 +30,204 @@ impl AoCProject {
             title: title.unwrap_or_else(|| format!("AoC {}", year)),
             author,
             url,
+            answers_dir: None,
         }
     }
-    pub fn run<S>(&self, days: &[Box<dyn AoCDay>], inputs: &HashMap<usize, S>) where S: AsRef<str> + std::fmt::Display {
+
+    /// Keeps answers in `dir`, usually `answers_dir!()`, so that they're
+    /// found however the binary is started. Otherwise they're only found
+    /// when it's run through cargo.
+    pub fn with_answers_dir(mut self, dir: PathBuf) -> Self {
+        self.answers_dir = Some(dir);
+        self
+    }
+    /// Identifies this project to the puzzle site when downloading inputs.
+    pub fn user_agent(&self) -> String {
+        format!(
+            "{} via aoc_core/{} ({})",
+            self.title,
+            env!("CARGO_PKG_VERSION"),
+            self.url.as_deref().unwrap_or(&self.author)
+        )
+    }
+
+    /// A client for the puzzle site, configured from the environment.
+    pub fn client(&self) -> AoCClient {
+        AoCClient::from_env(self.year, self.user_agent())
+    }
+
+    /// Runs the CLI and exits the process with the resulting `ExitStatus`.
+    pub fn run<I>(&self, days: &[Box<dyn AoCDay>], inputs: &I) -> ! where I: InputSource + ?Sized {
+        std::process::exit(self.execute(days, inputs).code())
+    }
+
+    /// Runs the CLI, returning how it went rather than exiting.
+    pub fn execute<I>(&self, days: &[Box<dyn AoCDay>], inputs: &I) -> ExitStatus where I: InputSource + ?Sized {
+        self.execute_from(std::env::args_os(), days, inputs)
+    }
+
+    /// Like `execute`, but with the command line `args`, starting with the
+    /// name of the binary, rather than those of the process.
+    pub fn execute_from<A, T, I>(&self, args: A, days: &[Box<dyn AoCDay>], inputs: &I) -> ExitStatus
+    where
+        A: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+        I: InputSource + ?Sized,
+    {
         let matches = clap_app!(aoc_core =>
             (version: self.version.as_str())
             (author: self.author.as_str())
             (about: format!("Solutions for Advent of Code {}", self.year).as_str())
             (setting: AppSettings::SubcommandRequiredElseHelp)
             (@arg verbose: --verbose -v ... "Sets the level of verbosity")
+            (@arg answers: --answers +takes_value +global "directory of recorded answer files (default src/answers)")
             (@subcommand run =>
                 (about: "executes requested solution(s)")
-                (@arg day: --day -d +takes_value "indicate a specific day")
-                (@arg part: --part -p +takes_value #{1,2} "indicate a specific part")
-                // TODO: (@arg stdin: --stdin -i "flag for providing input over stdin")
-                (@arg input_file: --("input-file") -f +takes_value "location of an input file")
+                (@arg day: --day -d +takes_value "indicate a specific day, or a list of days and ranges such as 1-5,9,12-")
+                (@arg exclude: --exclude -x +takes_value "days to skip, in the same format as --day")
+                (@arg part: --part -p +takes_value "indicate a specific part")
+                (@arg stdin: --stdin -i conflicts_with[input_file] "read input from stdin (same as --input-file -)")
+                (@arg input_file: --("input-file") -f +takes_value "location of an input file, or - for stdin")
+                (@arg input: --input +takes_value "name of the input to run, e.g. example1, or whose answers to check --input-file against")
+                (@arg all_inputs: --("all-inputs") -a conflicts_with[input input_file stdin] "run every input of each day")
+                (@arg jobs: --jobs -j +takes_value "number of days run at once (0 for one per CPU)")
+                (@arg split_parts: --("split-parts") "run each part of a day as a separate job")
+                (@arg timeout: --timeout -t +takes_value "seconds to wait on each part before reporting it as timed out")
+                (@arg isolate: --isolate "run each part in a child process which can be killed on timeout")
+                (@arg format: --format +takes_value possible_values(&["text", "json", "csv"]) default_value("text") "output format")
+                (@arg watch: --watch -w conflicts_with[stdin] "re-run a single day whenever its input file changes")
+                (@arg watch_sources: --("watch-sources") +takes_value requires[watch] "also rebuild and restart when a .rs file under this directory changes")
+                (@arg interval: --interval +takes_value requires[watch] "seconds between checks for changes (default 0.5)")
+            )
+            (@subcommand bench =>
+                (about: "benchmarks a solution over many iterations")
+                (@arg day: --day -d +takes_value +required "day to benchmark")
+                (@arg part: --part -p +takes_value "indicate a specific part")
+                (@arg input_file: --("input-file") -f +takes_value "location of an input file, or - for stdin")
+                (@arg input: --input +takes_value "name of the input to benchmark against, e.g. example1")
+                (@arg warmup: --warmup -w +takes_value "number of untimed warmup iterations (default 3)")
+                (@arg iterations: --iterations -n +takes_value conflicts_with[time] "number of timed iterations (default 100)")
+                (@arg time: --time -t +takes_value "time budget for timed iterations, in seconds")
+            )
+            (@subcommand verify =>
+                (about: "checks every input of every day against its expected answers")
+                (@arg jobs: --jobs -j +takes_value "number of days run at once (0 for one per CPU)")
+                (@arg split_parts: --("split-parts") "run each part of a day as a separate job")
+                (@arg timeout: --timeout -t +takes_value "seconds to wait on each part before reporting it as timed out")
+                (@arg isolate: --isolate "run each part in a child process which can be killed on timeout")
+            )
+            (@subcommand progress =>
+                (about: "shows a calendar of which days and parts are solved, failing or missing")
+                (@arg jobs: --jobs -j +takes_value "number of days run at once (0 for one per CPU)")
+                (@arg timeout: --timeout -t +takes_value "seconds to wait on each part before reporting it as timed out")
+                (@arg isolate: --isolate "run each part in a child process which can be killed on timeout")
+            )
+            (@subcommand record =>
+                (about: "runs solutions and records their answers as the expected ones")
+                (@arg day: --day -d +takes_value "indicate a specific day, or a list of days and ranges such as 1-5,9,12-")
+                (@arg part: --part -p +takes_value "indicate a specific part")
+                (@arg input: --input +takes_value "name of the input to record answers for, e.g. example1")
+                (@arg all_inputs: --("all-inputs") -a conflicts_with[input] "record answers for every input of each day")
+                (@arg yes: --yes -y "record without asking for confirmation")
+            )
+            (@subcommand guess =>
+                (about: "records an answer submitted to the puzzle site and its verdict, or lists earlier guesses")
+                (@arg day: --day -d +takes_value +required "day the answer is for")
+                (@arg part: --part -p +takes_value "part the answer is for")
+                (@arg input: --input +takes_value "name of the input the answer is for (default: the main input)")
+                (@arg input_file: --("input-file") -f +takes_value "location of the input the answer is for")
+                (@arg answer: --answer +takes_value requires[verdict part] "the submitted answer")
+                (@arg verdict: --verdict +takes_value requires[answer] possible_values(&["correct", "too-high", "too-low", "wrong"]) "what the puzzle site said about the answer")
+            )
+            (@subcommand submit =>
+                (about: "computes an answer and submits it to the puzzle site")
+                (@arg day: --day -d +takes_value +required "day to submit")
+                (@arg part: --part -p +takes_value +required "part to submit")
+                (@arg answer: --answer +takes_value "submit this answer instead of computing it")
+                (@arg force: --force "submit even if the answer is known to be wrong")
+            )
+            (@subcommand puzzle =>
+                (about: "shows a puzzle description as Markdown, and saves its examples as inputs")
+                (@arg day: --day -d +takes_value +required "day of the puzzle")
+                (@arg file: --file -f +takes_value "read the puzzle page from a saved HTML file instead of downloading it")
+                (@arg output: --output -o +takes_value "write the Markdown to this file instead of stdout")
+                (@arg examples: --examples -e "save the puzzle's code blocks as the day's example1, example2... inputs")
+                (@arg overwrite: --overwrite requires[examples] "replace example inputs which already exist")
+            )
+            (@subcommand leaderboard =>
+                (about: "shows the rankings of a private leaderboard, or its times for one day")
+                (@arg id: --id +takes_value conflicts_with[file] "id of the leaderboard (default $AOC_LEADERBOARD)")
+                (@arg file: --file -f +takes_value "read the leaderboard from a saved JSON file instead of downloading it")
+                (@arg day: --day -d +takes_value "show how long each member took on this day")
             )
             (@subcommand list =>
                 (about: "lists implemented days")
             )
         )
-        .get_matches();
+        .get_matches_from_safe(args);
 
-        let mut day: Option<usize> = None;
+        let matches = match matches {
+            Ok(m) => m,
+            Err(e) if e.kind == ErrorKind::HelpDisplayed || e.kind == ErrorKind::VersionDisplayed => {
+                println!("{}", e.message);
+                return ExitStatus::Passed;
+            }
+            Err(e) => {
+                eprintln!("{}", e.message);
+                return ExitStatus::Usage;
+            }
+        };
+
+        let answers_dir = matches
+            .value_of("answers")
+            .map(PathBuf::from)
+            .or_else(|| self.answers_dir.clone())
+            .or_else(default_answers_dir);
+        // What results are checked against, only loaded for the subcommands
+        // which check them
+        let checks = match matches.subcommand_name() {
+            Some("run" | "verify" | "progress" | "record" | "submit") => match load_checks(answers_dir.as_deref()) {
+                Ok(checks) => checks,
+                Err(status) => return status,
+            },
+            _ => RunOptions::default(),
+        };
+
+        // Days without an embedded input fall back to downloading theirs
+        let inputs = &WithDownloads {
+            inputs,
+            client: self.client(),
+        };
+
+        let mut selection: Option<DaySelection> = None;
+        let mut exclude: Option<DaySelection> = None;
         let mut part: Option<usize> = None;
         let mut err: bool = false;
+        let mut status = ExitStatus::Passed;
 
         if let Some(run_cmd) = matches.subcommand_matches("run") {
             if let Some(day_str) = run_cmd.value_of("day") {
-                match day_str.parse::<usize>() {
-                    Ok(d) => day = Some(d),
+                match day_str.parse::<DaySelection>() {
+                    Ok(d) => selection = Some(d),
                     Err(_) => {
                         err = true;
                         eprintln!("Unable to parse day value");
                     }
                 }
             }
+            if let Some(exclude_str) = run_cmd.value_of("exclude") {
+                match exclude_str.parse::<DaySelection>() {
+                    Ok(d) => exclude = Some(d),
+                    Err(_) => {
+                        err = true;
+                        eprintln!("Unable to parse exclude value");
+                    }
+                }
+            }
             if let Some(part_str) = run_cmd.value_of("part") {
                 match part_str.parse::<usize>() {
                     Ok(p) => {
-                        if p == 1 || p == 2 {
+                        if p >= 1 {
                             part = Some(p);
                         } else {
                             err = true;
@@ -77,113 +241,859 @@ impl AoCProject {
                 }
             }
 
-            if !err {
-                match day {
-                    None => {
-                        if run_cmd.value_of("part").is_some() {
-                            eprintln!("Cannot specify part without day!");
-                        } else {
-                            for d in days.iter() {
-                                if let Some(input) = inputs.get(&d.day()) {
-                                    run_day(d, None, input);
-                                } else {
-                                    eprintln!("Input not found for day {}", d.day());
-                                }
+            let format = run_cmd
+                .value_of("format")
+                .unwrap_or("text")
+                .parse::<OutputFormat>()
+                .unwrap_or(OutputFormat::Text);
+            let mut renderer = format.renderer();
+
+            let mut options = parse_run_options(run_cmd, &checks, &mut err);
+            options.part = part;
+            let input_name = match run_cmd.is_present("all_inputs") {
+                true => None,
+                false => Some(run_cmd.value_of("input").unwrap_or(DEFAULT_INPUT)),
+            };
+
+            if err {
+                return ExitStatus::Usage;
+            }
+            let single_day = match (&selection, &exclude) {
+                (Some(selection), None) => selection.single(),
+                _ => None,
+            };
+            match single_day {
+                None => {
+                    if selection.is_none() && run_cmd.value_of("part").is_some() {
+                        eprintln!("Cannot specify part without day!");
+                        status = ExitStatus::Usage;
+                    } else if run_cmd.is_present("watch") {
+                        eprintln!("Cannot watch without a single day!");
+                        status = ExitStatus::Usage;
+                    } else if run_cmd.is_present("stdin") || run_cmd.value_of("input_file") == Some("-") {
+                        eprintln!("Cannot read input from stdin without a single day!");
+                        status = ExitStatus::Usage;
+                    } else if run_cmd.is_present("input_file") {
+                        eprintln!("Cannot specify input file without a single day!");
+                        status = ExitStatus::Usage;
+                    } else {
+                        let selection = selection.unwrap_or_else(DaySelection::all);
+                        for d in selection.explicit_days() {
+                            if !days.iter().any(|day| day.day() == d) {
+                                eprintln!("Implementation not found for day {}", d);
+                                status = ExitStatus::Missing;
                             }
                         }
+                        let selected: Vec<&Box<dyn AoCDay>> = days
+                            .iter()
+                            .filter(|d| selection.contains(d.day()))
+                            .filter(|d| !exclude.as_ref().is_some_and(|e| e.contains(d.day())))
+                            .collect();
+                        // Days without the requested part are skipped, as for `record`
+                        let with_part: Vec<&Box<dyn AoCDay>> = selected
+                            .iter()
+                            .copied()
+                            .filter(|d| part.is_none_or(|p| p <= d.parts()))
+                            .collect();
+                        if let Some(p) = part.filter(|_| with_part.is_empty() && !selected.is_empty()) {
+                            eprintln!("None of the selected days has part {}", p);
+                            return ExitStatus::Usage;
+                        }
+                        let (owned_inputs, missing) = gather_inputs(with_part.into_iter(), inputs, input_name);
+                        if !missing.is_empty() {
+                            status = ExitStatus::Missing;
+                        }
+                        let work: Vec<(&Box<dyn AoCDay>, Input)> =
+                            owned_inputs.iter().map(|(d, n, c)| (*d, Input::named(n, c))).collect();
+                        let report = solve_days_parallel(&work, &options, renderer.as_mut());
+                        status = status.max(ExitStatus::from_report(&report));
                     }
-                    Some(selected_day) => {
-                        let mut input_opt = None;
-
-                        if let Some(path) = run_cmd.value_of("input_file") {
-                            input_opt = Some(std::fs::read_to_string(path).expect(""));
+                }
+                Some(selected_day) => {
+                    let input_file = match run_cmd.is_present("stdin") {
+                        true => Some("-"),
+                        false => run_cmd.value_of("input_file"),
+                    };
+                    if let Some(day) = days.iter().find(|d| d.day() == selected_day) {
+                        if let Some(p) = part.filter(|p| *p > day.parts()) {
+                            eprintln!("Day {} has no part {}", selected_day, p);
+                            return ExitStatus::Usage;
                         }
-
-                        if input_opt == None {
-                            input_opt = inputs.get(&selected_day).map(|s| s.to_string());
+                        if run_cmd.is_present("watch") {
+                            if input_file == Some("-") {
+                                eprintln!("Cannot watch stdin!");
+                                return ExitStatus::Usage;
+                            }
+                            let mut watch = WatchOptions {
+                                input: input_file.map_or_else(
+                                    || default_input_path(selected_day, input_name.unwrap_or(DEFAULT_INPUT)),
+                                    PathBuf::from,
+                                ),
+                                sources: run_cmd.value_of("watch_sources").map(PathBuf::from),
+                                interval: Duration::from_millis(500),
+                            };
+                            if let Some(secs) = run_cmd.value_of("interval") {
+                                match secs.parse::<f64>() {
+                                    Ok(t) if t > 0.0 && t.is_finite() => watch.interval = Duration::from_secs_f64(t),
+                                    _ => {
+                                        eprintln!("Invalid interval value");
+                                        return ExitStatus::Usage;
+                                    }
+                                }
+                            }
+                            if !watch.input.is_file() {
+                                eprintln!("Input not found at {}", watch.input.display());
+                                return ExitStatus::Missing;
+                            }
+                            return watch_day(day, &options, &watch);
                         }
-
-                        if let Some(input) = input_opt {
-                            if let Some(day) = days.iter().find(|d| d.day() == selected_day) {
-                                run_day(day, part, input);
-                            } else {
-                                eprintln!("Implementation not found for day {}", selected_day);
+                        if input_name.is_none() {
+                            let (owned_inputs, missing) = gather_inputs(std::iter::once(day), inputs, None);
+                            if !missing.is_empty() {
+                                return ExitStatus::Missing;
                             }
-                        } else {
-                            eprintln!("Input not found for day {}", selected_day);
+                            let work: Vec<(&Box<dyn AoCDay>, Input)> =
+                                owned_inputs.iter().map(|(d, n, c)| (*d, Input::named(n, c))).collect();
+                            let report = solve_days_parallel(&work, &options, renderer.as_mut());
+                            return ExitStatus::from_report(&report);
                         }
+                        let name = input_name.unwrap_or(DEFAULT_INPUT);
+                        match load_input(selected_day, input_file, name, inputs) {
+                            Ok(Some(input)) => {
+                                let report = solve_days_parallel(&[(day, Input::named(name, &input))], &options, renderer.as_mut());
+                                status = ExitStatus::from_report(&report);
+                            }
+                            Ok(None) => {
+                                eprintln!("{}", missing_input_message(selected_day, name));
+                                status = ExitStatus::Missing;
+                            }
+                            Err(e) => {
+                                eprintln!("Unable to read input: {}", e);
+                                status = ExitStatus::Missing;
+                            }
+                        }
+                    } else {
+                        eprintln!("Implementation not found for day {}", selected_day);
+                        status = ExitStatus::Missing;
                     }
                 }
             }
+        } else if let Some(verify_cmd) = matches.subcommand_matches("verify") {
+            status = run_verify(verify_cmd, days, inputs, &checks);
+        } else if let Some(progress_cmd) = matches.subcommand_matches("progress") {
+            status = run_progress(progress_cmd, days, inputs, &checks);
+        } else if let Some(record_cmd) = matches.subcommand_matches("record") {
+            status = match writable_answers_dir(answers_dir.as_deref()) {
+                Some(dir) => run_record(record_cmd, days, inputs, &checks, dir),
+                None => ExitStatus::Usage,
+            };
+        } else if let Some(submit_cmd) = matches.subcommand_matches("submit") {
+            status = match writable_answers_dir(answers_dir.as_deref()) {
+                Some(dir) => run_submit(submit_cmd, days, inputs, &checks, dir),
+                None => ExitStatus::Usage,
+            };
+        } else if let Some(puzzle_cmd) = matches.subcommand_matches("puzzle") {
+            status = run_puzzle(puzzle_cmd, &inputs.client);
+        } else if let Some(leaderboard_cmd) = matches.subcommand_matches("leaderboard") {
+            status = run_leaderboard(leaderboard_cmd, &inputs.client);
+        } else if let Some(guess_cmd) = matches.subcommand_matches("guess") {
+            status = match writable_answers_dir(answers_dir.as_deref()) {
+                Some(dir) => match GuessLog::load(dir) {
+                    Ok(guesses) => run_guess(guess_cmd, days, inputs, guesses, dir),
+                    Err(e) => {
+                        eprintln!("Unable to load guesses: {}", e);
+                        ExitStatus::Errored
+                    }
+                },
+                None => ExitStatus::Usage,
+            };
+        } else if let Some(bench_cmd) = matches.subcommand_matches("bench") {
+            status = run_bench(bench_cmd, days, inputs);
         } else if let Some(_list_cmd) = matches.subcommand_matches("list") {
             for d in days.iter() {
-                println!("Day {:>1}", d.day());
+                let names: Vec<String> = inputs
+                    .names(d.day())
+                    .into_iter()
+                    .filter(|n| n != DEFAULT_INPUT)
+                    .collect();
+                match names.is_empty() {
+                    true => println!("Day {:>1}", d.day()),
+                    false => println!("Day {:>1} (+ {})", d.day(), names.join(", ")),
+                }
             }
         }
+        status
     }
 }
 
-#[allow(clippy::redundant_pattern_matching, clippy::borrowed_box)]
-pub fn run_day<S>(day: &Box<dyn AoCDay>, part: Option<usize>, input: S) where S: AsRef<str> + std::fmt::Display {
-    match part {
-        Some(p) => {
-            let (expected, val) = match p {
-                1 => (day.expected().0, day.part1(input.as_ref())),
-                2 => (day.expected().1, day.part2(input.as_ref())),
-                _ => unreachable!(),
+// The recorded answers and earlier guesses in `dir`, as options to run days
+// with. Nothing is recorded without a directory.
+fn load_checks(dir: Option<&Path>) -> Result<RunOptions, ExitStatus> {
+    let Some(dir) = dir else {
+        return Ok(RunOptions::default());
+    };
+    let answers = Answers::load(dir).map_err(|e| {
+        eprintln!("Unable to load answers: {}", e);
+        ExitStatus::Errored
+    })?;
+    let guesses = GuessLog::load(dir).map_err(|e| {
+        eprintln!("Unable to load guesses: {}", e);
+        ExitStatus::Errored
+    })?;
+    Ok(RunOptions {
+        answers,
+        guesses,
+        ..RunOptions::default()
+    })
+}
+
+// The directory to record answers and guesses in, for subcommands which
+// can't do without one.
+fn writable_answers_dir(dir: Option<&Path>) -> Option<&Path> {
+    if dir.is_none() {
+        eprintln!("Unable to locate the answers directory, pass one with --answers");
+    }
+    dir
+}
+
+// Exits with `status` rather than waiting on timed out parts which are still
+// running in-process, once their run has rendered its results.
+fn exit_stuck(status: ExitStatus) -> ! {
+    let _ = std::io::stdout().flush();
+    std::process::exit(status.code())
+}
+
+// Options shared by `run` and `verify`, on top of the answers and guesses in
+// `checks`. Sets `err` if any are invalid.
+fn parse_run_options(cmd: &ArgMatches, checks: &RunOptions, err: &mut bool) -> RunOptions {
+    let mut options = RunOptions {
+        split_parts: cmd.is_present("split_parts"),
+        on_stuck: Some(|report| exit_stuck(ExitStatus::from_report(report))),
+        ..checks.clone()
+    };
+    if let Some(jobs_str) = cmd.value_of("jobs") {
+        match jobs_str.parse::<usize>() {
+            Ok(0) => options.jobs = std::thread::available_parallelism().map_or(1, |n| n.get()),
+            Ok(j) => options.jobs = j,
+            Err(_) => {
+                *err = true;
+                eprintln!("Unable to parse jobs value");
+            }
+        }
+    }
+    if let Some(secs) = cmd.value_of("timeout") {
+        match secs.parse::<f64>() {
+            Ok(t) if t > 0.0 && t.is_finite() => options.timeout = Some(Duration::from_secs_f64(t)),
+            _ => {
+                *err = true;
+                eprintln!("Invalid timeout value");
+            }
+        }
+    }
+    if cmd.is_present("isolate") {
+        match std::env::current_exe() {
+            Ok(exe) => options.isolate = Some(exe),
+            Err(e) => {
+                *err = true;
+                eprintln!("Unable to locate executable for --isolate: {}", e);
+            }
+        }
+    }
+    options
+}
+
+// Runs every day against its embedded input, failing if any part with a
+// known answer doesn't match it.
+fn run_verify<I>(cmd: &ArgMatches, days: &[Box<dyn AoCDay>], inputs: &I, checks: &RunOptions) -> ExitStatus where I: InputSource + ?Sized {
+    let mut err = false;
+    let mut options = parse_run_options(cmd, checks, &mut err);
+    options.on_stuck = Some(|report| exit_stuck(ExitStatus::from_verified(report)));
+    if err {
+        return ExitStatus::Usage;
+    }
+
+    let mut status = ExitStatus::Passed;
+    let (owned_inputs, missing) = gather_inputs(days.iter(), inputs, None);
+    for day in days.iter().filter(|d| missing.contains(&d.day())) {
+        let (part1, part2) = day.expected();
+        if part1.is_some() || part2.is_some() || checks.answers.has_day(day.day()) {
+            status = ExitStatus::Missing;
+        }
+    }
+    let work: Vec<(&Box<dyn AoCDay>, Input)> =
+        owned_inputs.iter().map(|(d, n, c)| (*d, Input::named(n, c))).collect();
+    let mut renderer = SummaryRenderer::new(std::io::stdout());
+    let report = solve_days_parallel(&work, &options, &mut renderer);
+    status.max(ExitStatus::from_verified(&report))
+}
+
+// Runs every day against its main input and shows the results as a
+// calendar. Inputs are never downloaded for this, only read from the cache.
+fn run_progress(
+    cmd: &ArgMatches,
+    days: &[Box<dyn AoCDay>],
+    inputs: &WithDownloads<impl InputSource + ?Sized>,
+    checks: &RunOptions,
+) -> ExitStatus {
+    let mut err = false;
+    let mut options = parse_run_options(cmd, checks, &mut err);
+    options.on_stuck = Some(|_| exit_stuck(ExitStatus::Passed));
+    if err {
+        return ExitStatus::Usage;
+    }
+
+    let cached = WithDownloads {
+        inputs: inputs.inputs,
+        client: AoCClient {
+            session: None,
+            ..inputs.client.clone()
+        },
+    };
+    let mut contents = vec![];
+    let mut missing = vec![];
+    for day in days.iter() {
+        match cached.get(day.day(), DEFAULT_INPUT) {
+            Some(content) => contents.push((day, content)),
+            None => missing.push(day.day()),
+        }
+    }
+    let work: Vec<(&Box<dyn AoCDay>, Input)> = contents.iter().map(|(d, c)| (*d, Input::from(c))).collect();
+    let implemented = days.iter().map(|d| d.day()).collect();
+    let mut renderer = ProgressRenderer::new(std::io::stdout(), implemented, missing);
+    solve_days_parallel(&work, &options, &mut renderer);
+    ExitStatus::Passed
+}
+
+// Runs the selected days and, once confirmed, records every answer which
+// differs from the expected one in the answer files under `dir`.
+fn run_record<I>(cmd: &ArgMatches, days: &[Box<dyn AoCDay>], inputs: &I, checks: &RunOptions, dir: &Path) -> ExitStatus where I: InputSource + ?Sized {
+    let selection = match cmd.value_of("day").map(str::parse::<DaySelection>) {
+        None => DaySelection::all(),
+        Some(Ok(s)) => s,
+        Some(Err(_)) => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
+        }
+    };
+    let part = match cmd.value_of("part").map(str::parse::<usize>) {
+        None => None,
+        Some(Ok(p)) if p >= 1 => Some(p),
+        Some(Ok(_)) => {
+            eprintln!("Invalid part value");
+            return ExitStatus::Usage;
+        }
+        Some(Err(_)) => {
+            eprintln!("Unable to parse part value");
+            return ExitStatus::Usage;
+        }
+    };
+    let input_name = match cmd.is_present("all_inputs") {
+        true => None,
+        false => Some(cmd.value_of("input").unwrap_or(DEFAULT_INPUT)),
+    };
+
+    let mut status = ExitStatus::Passed;
+    for d in selection.explicit_days() {
+        if !days.iter().any(|day| day.day() == d) {
+            eprintln!("Implementation not found for day {}", d);
+            status = ExitStatus::Missing;
+        }
+    }
+    // Days without the requested part are left out
+    let selected = days
+        .iter()
+        .filter(|d| selection.contains(d.day()))
+        .filter(|d| part.is_none_or(|p| p <= d.parts()));
+    let (owned_inputs, missing) = gather_inputs(selected, inputs, input_name);
+    if !missing.is_empty() {
+        status = ExitStatus::Missing;
+    }
+    let work: Vec<(&Box<dyn AoCDay>, Input)> =
+        owned_inputs.iter().map(|(d, n, c)| (*d, Input::named(n, c))).collect();
+    let options = RunOptions { part, ..checks.clone() };
+    let report = solve_days_parallel(&work, &options, &mut TextRenderer::stdout());
+    if report.results.iter().any(|r| r.answer.is_none()) {
+        status = ExitStatus::Errored;
+    }
+    let mut answers = checks.answers.clone();
+
+    // Answers matching the day's expected() are recorded too, tying them to
+    // the content of the input they came from
+    let changes: Vec<(&PartResult, &Input)> = report
+        .results
+        .iter()
+        .filter_map(|r| {
+            let input = work.iter().find(|(d, i)| d.day() == r.day && i.name == r.input)?;
+            Some((r, &input.1))
+        })
+        // Answers already known to be wrong are never recorded
+        .filter(|(r, _)| r.status != TestStatus::Rejected)
+        .filter(|(r, input)| match (r.answer.as_deref(), answers.lookup(r.day, r.part, input)) {
+            (Some(answer), RecordedAnswer::Known(recorded)) => answer != recorded,
+            (answer, _) => answer.is_some(),
+        })
+        .collect();
+    if changes.is_empty() {
+        println!("\nNo new answers to record");
+        return status;
+    }
+    println!();
+    for (r, _) in changes.iter() {
+        let label = match r.input.as_str() {
+            DEFAULT_INPUT => format!("Day {:02}", r.day),
+            name => format!("Day {:02} ({})", r.day, name),
+        };
+        match r.expected.as_deref() {
+            Some(expected) if expected != r.output() => {
+                println!("{}, Part {}: {} (was {})", label, r.part, r.output(), expected)
+            }
+            _ => println!("{}, Part {}: {} (new)", label, r.part, r.output()),
+        }
+    }
+
+    if !cmd.is_present("yes") {
+        print!("Record {} answer(s) in {}? [y/N] ", changes.len(), dir.display());
+        let _ = std::io::stdout().flush();
+        let mut reply = String::new();
+        if std::io::stdin().read_line(&mut reply).is_err() || !matches!(reply.trim(), "y" | "Y" | "yes") {
+            println!("Nothing recorded");
+            return status;
+        }
+    }
+    let mut changed_days: Vec<usize> = vec![];
+    for (r, input) in changes.iter() {
+        answers.insert_for(r.day, r.part, input, r.output().to_string());
+        if !changed_days.contains(&r.day) {
+            changed_days.push(r.day);
+        }
+    }
+    for day in changed_days {
+        if let Err(e) = answers.save_day(dir, day) {
+            eprintln!("Unable to record answers for day {}: {}", day, e);
+            return ExitStatus::Errored;
+        }
+    }
+    println!("Recorded {} answer(s)", changes.len());
+    status
+}
+
+// Computes the answer to a part of the main input and submits it, recording
+// the verdict in the guess log and a correct answer in the answer files.
+fn run_submit(
+    cmd: &ArgMatches,
+    days: &[Box<dyn AoCDay>],
+    inputs: &WithDownloads<impl InputSource + ?Sized>,
+    checks: &RunOptions,
+    dir: &Path,
+) -> ExitStatus {
+    let day = match cmd.value_of("day").map(str::parse::<usize>) {
+        Some(Ok(d)) => d,
+        _ => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
+        }
+    };
+    let part = match cmd.value_of("part").map(str::parse::<usize>) {
+        Some(Ok(p)) if p == 1 || p == 2 => p,
+        Some(Ok(_)) => {
+            eprintln!("Invalid part value");
+            return ExitStatus::Usage;
+        }
+        _ => {
+            eprintln!("Unable to parse part value");
+            return ExitStatus::Usage;
+        }
+    };
+    let content = match inputs.get(day, DEFAULT_INPUT) {
+        Some(content) => content,
+        None => {
+            eprintln!("{}", missing_input_message(day, DEFAULT_INPUT));
+            return ExitStatus::Missing;
+        }
+    };
+    let input = Input::from(&content);
+
+    let mut result = match (cmd.value_of("answer"), days.iter().find(|d| d.day() == day)) {
+        (Some(answer), _) => {
+            let mut result = PartResult {
+                answer: Some(answer.to_string()),
+                ..PartResult::new(day, part, DEFAULT_INPUT)
             };
+            checks.answers.check(&mut result, &input);
+            checks.guesses.check(&mut result, &input);
+            result
+        }
+        (None, Some(implementation)) => {
+            let options = RunOptions {
+                part: Some(part),
+                ..checks.clone()
+            };
+            let report = solve_days_parallel(&[(implementation, input)], &options, &mut TextRenderer::stdout());
+            report.results.into_iter().next().unwrap()
+        }
+        (None, None) => {
+            eprintln!("Implementation not found for day {}", day);
+            return ExitStatus::Missing;
+        }
+    };
+    let answer = match result.answer.take() {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Day {:02}, Part {}: no answer to submit", day, part);
+            return ExitStatus::Errored;
+        }
+    };
+    if !cmd.is_present("force") {
+        match result.status {
+            TestStatus::Success => {
+                println!("Day {:02}, Part {}: {} is already known to be correct", day, part, answer);
+                return ExitStatus::Passed;
+            }
+            TestStatus::Failure | TestStatus::Rejected => {
+                let reason = match result.expected.as_deref() {
+                    Some(expected) => format!("the answer is {}", expected),
+                    None => result.error.unwrap_or_default(),
+                };
+                eprintln!("Not submitting {}, {} (use --force to submit anyway)", answer, reason);
+                return ExitStatus::WrongAnswer;
+            }
+            _ => {}
+        }
+    }
+
+    let client = &inputs.client;
+    let outcome = match client.submit(day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Unable to submit answer: {}", e);
+            return ExitStatus::Errored;
+        }
+    };
+    println!("Day {:02}, Part {}: {} is {}", day, part, answer, outcome);
+    let verdict = match outcome {
+        SubmitOutcome::Verdict(verdict) => verdict,
+        _ => return ExitStatus::Errored,
+    };
+    let mut guesses = checks.guesses.clone();
+    if let Err(e) = guesses.record(dir, day, part, &input, verdict, &answer) {
+        eprintln!("Unable to record guess: {}", e);
+        return ExitStatus::Errored;
+    }
+    if verdict != Verdict::Correct {
+        return ExitStatus::WrongAnswer;
+    }
+    let mut answers = checks.answers.clone();
+    answers.insert_for(day, part, &input, answer);
+    if let Err(e) = answers.save_day(dir, day) {
+        eprintln!("Unable to record answer: {}", e);
+        return ExitStatus::Errored;
+    }
+    ExitStatus::Passed
+}
+
+fn run_puzzle(cmd: &ArgMatches, client: &AoCClient) -> ExitStatus {
+    let day = match cmd.value_of("day").map(str::parse::<usize>) {
+        Some(Ok(d)) => d,
+        _ => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
+        }
+    };
+    let html = match cmd.value_of("file") {
+        Some(file) => std::fs::read_to_string(file).map_err(ErrorWrapper::from),
+        None => client.fetch_puzzle(day),
+    };
+    let html = match html {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Unable to get puzzle: {}", e);
+            return ExitStatus::Missing;
+        }
+    };
+
+    let markdown = puzzle_markdown(&html);
+    match cmd.value_of("output") {
+        Some(output) => {
+            if let Err(e) = std::fs::write(output, markdown) {
+                eprintln!("Unable to write puzzle: {}", e);
+                return ExitStatus::Errored;
+            }
+        }
+        None => print!("{}", markdown),
+    }
+    if cmd.is_present("examples") {
+        let examples = extract_examples(&html);
+        match save_examples(day, &examples, cmd.is_present("overwrite")) {
+            Ok(written) => {
+                for path in written.iter() {
+                    eprintln!("Saved {}", path.display());
+                }
+                if written.len() < examples.len() {
+                    eprintln!("Kept {} existing example(s), use --overwrite to replace them", examples.len() - written.len());
+                }
+            }
+            Err(e) => {
+                eprintln!("Unable to save examples: {}", e);
+                return ExitStatus::Errored;
+            }
+        }
+    }
+    ExitStatus::Passed
+}
 
-            let (status1, value) = check_status(expected, val);
-            println!(
-                "Day {:02}, Part {}: {} {}",
-                day.day(),
-                p,
-                status1,
-                value
-            );
+fn run_leaderboard(cmd: &ArgMatches, client: &AoCClient) -> ExitStatus {
+    let day = match cmd.value_of("day").map(str::parse::<usize>) {
+        None => None,
+        Some(Ok(d)) => Some(d),
+        Some(Err(_)) => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
         }
+    };
+    let board = match cmd.value_of("file") {
+        Some(file) => std::fs::read_to_string(file)
+            .map_err(ErrorWrapper::from)
+            .and_then(|json| Leaderboard::parse(&json)),
         None => {
-            let (status1, value1) = check_status(day.expected().0, day.part1(input.as_ref()));
-            let (status2, value2) = check_status(day.expected().1, day.part2(input.as_ref()));
-            println!("Day {:02}, Part 1: {} {}", day.day(), status1, value1);
-            println!("        Part 2: {} {}", status2, value2);
+            let id = cmd
+                .value_of("id")
+                .map(str::to_string)
+                .or_else(|| std::env::var("AOC_LEADERBOARD").ok());
+            match id.map(|id| id.parse::<u64>()) {
+                Some(Ok(id)) => client.fetch_leaderboard(id),
+                Some(Err(_)) => {
+                    eprintln!("Unable to parse leaderboard id");
+                    return ExitStatus::Usage;
+                }
+                None => {
+                    eprintln!("Specify a leaderboard with --id, --file or $AOC_LEADERBOARD");
+                    return ExitStatus::Usage;
+                }
+            }
         }
+    };
+    let board = match board {
+        Ok(board) => board,
+        Err(e) => {
+            eprintln!("Unable to get leaderboard: {}", e);
+            return ExitStatus::Missing;
+        }
+    };
+    let mut out = std::io::stdout();
+    let written = match day {
+        Some(day) => render_day(&board, day, &mut out),
+        None => render_rankings(&board, &mut out),
+    };
+    match written {
+        Ok(()) => ExitStatus::Passed,
+        Err(_) => ExitStatus::Errored,
     }
 }
 
+// Records a guess at a part, or lists the earlier guesses at a day.
+fn run_guess<I>(cmd: &ArgMatches, days: &[Box<dyn AoCDay>], inputs: &I, mut guesses: GuessLog, dir: &Path) -> ExitStatus where I: InputSource + ?Sized {
+    let day = match cmd.value_of("day").map(str::parse::<usize>) {
+        Some(Ok(d)) => d,
+        _ => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
+        }
+    };
+    let parts = match cmd.value_of("part").map(str::parse::<usize>) {
+        // Days which aren't implemented yet are assumed to have two parts
+        None => (1..=days.iter().find(|d| d.day() == day).map_or(2, |d| d.parts())).collect(),
+        Some(Ok(p)) if p >= 1 => vec![p],
+        Some(Ok(_)) => {
+            eprintln!("Invalid part value");
+            return ExitStatus::Usage;
+        }
+        Some(Err(_)) => {
+            eprintln!("Unable to parse part value");
+            return ExitStatus::Usage;
+        }
+    };
+    let name = cmd.value_of("input").unwrap_or(DEFAULT_INPUT);
+    let content = match load_input(day, cmd.value_of("input_file"), name, inputs) {
+        Ok(Some(content)) => content,
+        Ok(None) => {
+            eprintln!("{}", missing_input_message(day, name));
+            return ExitStatus::Missing;
+        }
+        Err(e) => {
+            eprintln!("Unable to read input: {}", e);
+            return ExitStatus::Missing;
+        }
+    };
+    let input = Input::named(name, &content);
+
+    if let (Some(answer), Some(verdict)) = (cmd.value_of("answer"), cmd.value_of("verdict")) {
+        let verdict = verdict.parse::<Verdict>().unwrap_or(Verdict::Wrong);
+        if let Err(e) = guesses.record(dir, day, parts[0], &input, verdict, answer) {
+            eprintln!("Unable to record guess: {}", e);
+            return ExitStatus::Errored;
+        }
+        println!("Day {:02}, Part {}: recorded {} as {}", day, parts[0], answer, verdict);
+        return ExitStatus::Passed;
+    }
+    for part in parts {
+        let mut part_guesses = guesses.for_part(day, part, &input).peekable();
+        if part_guesses.peek().is_none() {
+            println!("Day {:02}, Part {}: no guesses", day, part);
+        }
+        for g in part_guesses {
+            println!("Day {:02}, Part {}: {} ({})", day, part, g.answer, g.verdict);
+        }
+    }
+    ExitStatus::Passed
+}
 
-#[derive(Debug)]
-enum TestStatus {
-    Unknown,
-    Failure,
-    Success,
+fn missing_input_message(day: usize, name: &str) -> String {
+    match name {
+        DEFAULT_INPUT => format!("Input not found for day {}", day),
+        _ => format!("Input {} not found for day {}", name, day),
+    }
 }
 
-impl std::fmt::Display for TestStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Unknown => write!(f, "?"),
-            Self::Failure => write!(f, "✗"),
-            Self::Success => write!(f, "✓"),
+// Looks up the input called `name` (or every input, if `None`) of each day,
+// returning `(day, name, content)` for each along with the days which had
+// none of them.
+#[allow(clippy::type_complexity, clippy::borrowed_box)]
+fn gather_inputs<'d, I>(
+    days: impl Iterator<Item = &'d Box<dyn AoCDay>>,
+    inputs: &I,
+    name: Option<&str>,
+) -> (Vec<(&'d Box<dyn AoCDay>, String, String)>, Vec<usize>) where I: InputSource + ?Sized {
+    let mut found = vec![];
+    let mut missing = vec![];
+    for day in days {
+        let names = match name {
+            Some(name) => vec![name.to_string()],
+            None => inputs.names(day.day()),
+        };
+        let before = found.len();
+        for name in names {
+            if let Some(content) = inputs.get(day.day(), &name) {
+                found.push((day, name, content));
+            }
+        }
+        if found.len() == before {
+            eprintln!("{}", missing_input_message(day.day(), name.unwrap_or(DEFAULT_INPUT)));
+            missing.push(day.day());
         }
     }
+    (found, missing)
 }
 
-fn check_status(
-    expected: Option<&str>,
-    value: Result<String, crate::ErrorWrapper>,
-) -> (TestStatus, String) {
-    match value {
-        Ok(val) => {
-            let status = match expected {
-                Some(v) => match v == val {
-                    true => TestStatus::Success,
-                    false => TestStatus::Failure,
-                },
-                None => TestStatus::Unknown,
-            };
-            (status, val)
+// An `input_file` of `-` reads the input from stdin.
+fn load_input<I>(day: usize, input_file: Option<&str>, name: &str, inputs: &I) -> Result<Option<String>, ErrorWrapper> where I: InputSource + ?Sized {
+    match input_file {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            Ok(Some(input))
+        }
+        Some(path) => Ok(Some(std::fs::read_to_string(path)?)),
+        None => Ok(inputs.get(day, name)),
+    }
+}
+
+fn run_bench<I>(cmd: &ArgMatches, days: &[Box<dyn AoCDay>], inputs: &I) -> ExitStatus where I: InputSource + ?Sized {
+    let mut options = BenchOptions::default();
+    let day = match cmd.value_of("day").map(str::parse::<usize>) {
+        Some(Ok(d)) => d,
+        _ => {
+            eprintln!("Unable to parse day value");
+            return ExitStatus::Usage;
+        }
+    };
+    let part = match cmd.value_of("part").map(str::parse::<usize>) {
+        None => None,
+        Some(Ok(p)) if p >= 1 => Some(p),
+        Some(Ok(_)) => {
+            eprintln!("Invalid part value");
+            return ExitStatus::Usage;
+        }
+        Some(Err(_)) => {
+            eprintln!("Unable to parse part value");
+            return ExitStatus::Usage;
+        }
+    };
+    if let Some(warmup) = cmd.value_of("warmup") {
+        match warmup.parse::<usize>() {
+            Ok(w) => options.warmup = w,
+            Err(_) => {
+                eprintln!("Unable to parse warmup value");
+                return ExitStatus::Usage;
+            }
+        }
+    }
+    if let Some(iterations) = cmd.value_of("iterations") {
+        match iterations.parse::<usize>() {
+            Ok(n) if n > 0 => options.limit = BenchLimit::Iterations(n),
+            _ => {
+                eprintln!("Invalid iterations value");
+                return ExitStatus::Usage;
+            }
+        }
+    }
+    if let Some(secs) = cmd.value_of("time") {
+        match secs.parse::<f64>() {
+            Ok(t) if t > 0.0 && t.is_finite() => options.limit = BenchLimit::Time(Duration::from_secs_f64(t)),
+            _ => {
+                eprintln!("Invalid time value");
+                return ExitStatus::Usage;
+            }
+        }
+    }
+
+    let implementation = match days.iter().find(|d| d.day() == day) {
+        Some(d) => d,
+        None => {
+            eprintln!("Implementation not found for day {}", day);
+            return ExitStatus::Missing;
+        }
+    };
+    let parts = match part {
+        None => (1..=implementation.parts()).collect(),
+        Some(p) if p <= implementation.parts() => vec![p],
+        Some(p) => {
+            eprintln!("Day {} has no part {}", day, p);
+            return ExitStatus::Usage;
+        }
+    };
+    let name = cmd.value_of("input").unwrap_or(DEFAULT_INPUT);
+    let input = match load_input(day, cmd.value_of("input_file"), name, inputs) {
+        Ok(Some(i)) => i,
+        Ok(None) => {
+            eprintln!("{}", missing_input_message(day, name));
+            return ExitStatus::Missing;
+        }
+        Err(e) => {
+            eprintln!("Unable to read input: {}", e);
+            return ExitStatus::Missing;
+        }
+    };
+
+    let mut status = ExitStatus::Passed;
+    for part in parts {
+        match bench_part(implementation, part, &input, &options) {
+            Ok(stats) => println!("Day {:02}, Part {}: {}", day, part, stats),
+            Err(e) => {
+                eprintln!("Day {:02}, Part {}: {}", day, part, e);
+                status = ExitStatus::Errored;
+            }
         }
-        Err(err) => (TestStatus::Failure, err.to_string()),
     }
+    status
+}
+
+/// Runs the requested part (or every part) of `day`, printing each answer
+/// along with how long it took, and returns the collected results.
+#[allow(clippy::borrowed_box)]
+pub fn run_day<S>(day: &Box<dyn AoCDay>, part: Option<usize>, input: S) -> RunReport where S: AsRef<str> + std::fmt::Display {
+    let report = solve_day(day, part, input.as_ref());
+    let mut renderer = TextRenderer::stdout();
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    report
 }
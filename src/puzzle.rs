@@ -0,0 +1,265 @@
+use crate::{default_input_path, AoCClient, ErrorWrapper};
+use std::path::PathBuf;
+
+enum Token<'a> {
+    // Tag name, lowercased, and the rest of the tag
+    Open(String, &'a str),
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(i) => rest.split_at(i),
+            None => (rest, ""),
+        };
+        if !text.is_empty() {
+            tokens.push(Token::Text(text));
+        }
+        let end = match tag.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let inner = &tag[1..end];
+        rest = &tag[end + 1..];
+        let (closing, inner) = match inner.strip_prefix('/') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+        let name_len = inner
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(inner.len());
+        let name = inner[..name_len].to_ascii_lowercase();
+        tokens.push(match closing {
+            true => Token::Close(name),
+            false => Token::Open(name, &inner[name_len..]),
+        });
+    }
+    tokens
+}
+
+// The value of `attr` in the attributes of a tag, `href="/2022"`.
+fn attribute<'a>(attrs: &'a str, attr: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", attr))? + attr.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+/// Replaces the HTML entities used on puzzle pages with the characters they
+/// stand for.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let entity = rest.find(';').filter(|end| *end <= 10).map(|end| &rest[1..end]);
+        let decoded = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some(' '),
+            Some(e) if e.starts_with("#x") => u32::from_str_radix(&e[2..], 16).ok().and_then(char::from_u32),
+            Some(e) if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match (decoded, entity) {
+            (Some(c), Some(e)) => {
+                out.push(c);
+                rest = &rest[e.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// The contents of every `<article>` in `html`, or all of it if there are
+// none, as is the case for a page saved without its surroundings.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        rest = &rest[start..];
+        let end = rest.find("</article>").unwrap_or(rest.len());
+        articles.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    match articles.is_empty() {
+        true => vec![html],
+        false => articles,
+    }
+}
+
+// Ends the current block with a blank line, unless nothing has been
+// written yet.
+fn end_block(out: &mut String) {
+    out.truncate(out.trim_end().len());
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+}
+
+// Closes inline markup, keeping a trailing space outside of it.
+fn end_inline(out: &mut String, markup: &str) {
+    let space = out.ends_with(' ');
+    if space {
+        out.pop();
+    }
+    out.push_str(markup);
+    if space {
+        out.push(' ');
+    }
+}
+
+/// Converts the puzzle description on a puzzle page to Markdown.
+pub fn puzzle_markdown(html: &str) -> String {
+    let mut out = String::new();
+    for article in articles(html) {
+        let mut pre = false;
+        // Markup inside code is shown as is, so emphasis there is dropped
+        let mut code = false;
+        let mut links: Vec<String> = vec![];
+        end_block(&mut out);
+        for token in tokens(article) {
+            match token {
+                Token::Open(name, attrs) => match name.as_str() {
+                    "h2" => {
+                        end_block(&mut out);
+                        out.push_str("## ");
+                    }
+                    "p" => end_block(&mut out),
+                    "pre" => {
+                        end_block(&mut out);
+                        out.push_str("```\n");
+                        pre = true;
+                    }
+                    "code" if !pre => {
+                        out.push('`');
+                        code = true;
+                    }
+                    "em" if !pre && !code => out.push('*'),
+                    "li" => {
+                        out.truncate(out.trim_end_matches(' ').len());
+                        if !out.is_empty() && !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("- ");
+                    }
+                    "br" => out.push('\n'),
+                    "a" => {
+                        out.push('[');
+                        links.push(attribute(attrs, "href").unwrap_or_default().to_string());
+                    }
+                    _ => {}
+                },
+                Token::Close(name) => match name.as_str() {
+                    "h2" | "p" | "ul" => end_block(&mut out),
+                    "pre" => {
+                        if !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("```");
+                        end_block(&mut out);
+                        pre = false;
+                    }
+                    "code" if !pre => {
+                        end_inline(&mut out, "`");
+                        code = false;
+                    }
+                    "em" if !pre && !code => end_inline(&mut out, "*"),
+                    "a" => end_inline(&mut out, &format!("]({})", links.pop().unwrap_or_default())),
+                    _ => {}
+                },
+                Token::Text(text) if pre => out.push_str(&decode_entities(text)),
+                Token::Text(text) => {
+                    // Whitespace outside of <pre> collapses to single spaces
+                    let text = decode_entities(text);
+                    let after_space = out.is_empty() || out.ends_with(char::is_whitespace);
+                    if text.starts_with(char::is_whitespace) && !after_space {
+                        out.push(' ');
+                    }
+                    out.push_str(&text.split_whitespace().collect::<Vec<&str>>().join(" "));
+                    if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+                        out.push(' ');
+                    }
+                }
+            }
+        }
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// The contents of every `<pre><code>` block on a puzzle page, which are
+/// usually its example inputs.
+pub fn extract_examples(html: &str) -> Vec<String> {
+    let mut examples = vec![];
+    let mut current: Option<String> = None;
+    let mut pre = false;
+    for token in tokens(html) {
+        match token {
+            Token::Open(name, _) if name == "pre" => pre = true,
+            Token::Close(name) if name == "pre" => pre = false,
+            Token::Open(name, _) if name == "code" && pre => current = Some(String::new()),
+            Token::Close(name) if name == "code" => {
+                if let Some(example) = current.take() {
+                    examples.push(example);
+                }
+            }
+            Token::Text(text) => {
+                if let Some(example) = current.as_mut() {
+                    example.push_str(&decode_entities(text));
+                }
+            }
+            _ => {}
+        }
+    }
+    examples
+}
+
+/// Saves `examples` as the `example1`, `example2`... inputs of `day`, laid
+/// out for `generate_get_inputs`. Existing inputs are kept unless
+/// `overwrite` is set. Returns the files written.
+pub fn save_examples(day: usize, examples: &[String], overwrite: bool) -> Result<Vec<PathBuf>, ErrorWrapper> {
+    let mut written = vec![];
+    for (i, example) in examples.iter().enumerate() {
+        let path = default_input_path(day, &format!("example{}", i + 1));
+        if path.exists() && !overwrite {
+            continue;
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut content = example.clone();
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        std::fs::write(&path, content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+impl AoCClient {
+    /// Downloads the puzzle page of `day`. Logged in, it includes part 2
+    /// once part 1 is solved.
+    pub fn fetch_puzzle(&self, day: usize) -> Result<String, ErrorWrapper> {
+        self.ensure_unlocked(day)?;
+        let (status, body) = self.request(&format!("/{}/day/{}", self.year, day), None)?;
+        match status {
+            200 => Ok(body),
+            _ => Err(ErrorWrapper::Simple(format!("Fetching puzzle failed with status {}", status))),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{format_duration, ErrorWrapper, PartResult, RunReport, TestStatus, DEFAULT_INPUT};
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl OutputFormat {
+    /// A renderer for this format which writes to stdout.
+    pub fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            OutputFormat::Text => Box::new(TextRenderer::stdout()),
+            OutputFormat::Json => Box::new(JsonRenderer::new(std::io::stdout())),
+            OutputFormat::Csv => Box::new(CsvRenderer::new(std::io::stdout())),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = ErrorWrapper;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(ErrorWrapper::ParseError(format!("Unknown output format: {}", s))),
+        }
+    }
+}
+
+/// Presents the results of a run. `day` is called as each day finishes so
+/// output can be streamed, and `finish` once with the complete report.
+pub trait Renderer {
+    fn day(&mut self, results: &[PartResult]);
+    fn finish(&mut self, _report: &RunReport) {}
+}
+
+/// The human-readable output used by the `run` subcommand.
+pub struct TextRenderer<W: Write> {
+    out: W,
+    days: usize,
+}
+
+impl<W: Write> TextRenderer<W> {
+    pub fn new(out: W) -> Self {
+        TextRenderer { out, days: 0 }
+    }
+}
+
+impl TextRenderer<std::io::Stdout> {
+    pub fn stdout() -> Self {
+        TextRenderer::new(std::io::stdout())
+    }
+}
+
+impl<W: Write> Renderer for TextRenderer<W> {
+    fn day(&mut self, results: &[PartResult]) {
+        for (i, r) in results.iter().enumerate() {
+            let prefix = match (i, r.input.as_str()) {
+                (0, DEFAULT_INPUT) => format!("Day {:02},", r.day),
+                (0, name) => format!("Day {:02} ({}),", r.day, name),
+                _ => "       ".to_string(),
+            };
+            let duration = match r.continued {
+                true => format!("{} after part 1", format_duration(r.duration)),
+                false => format_duration(r.duration),
+            };
+            // Multi-line answers, such as art, go on lines of their own
+            let art = r.output().trim_end().contains('\n');
+            match art {
+                true => write!(self.out, "{} Part {}: {} ({})", prefix, r.part, r.status, duration),
+                false => write!(self.out, "{} Part {}: {} {} ({})", prefix, r.part, r.status, r.output(), duration),
+            }
+            .unwrap();
+            match (r.status, r.error.as_deref()) {
+                (TestStatus::Rejected, Some(reason)) => writeln!(self.out, " - {}", reason),
+                _ => writeln!(self.out),
+            }
+            .unwrap();
+            if art {
+                for line in r.output().trim_end().lines() {
+                    writeln!(self.out, "        {}", line).unwrap();
+                }
+            }
+        }
+        let parse_duration = results.iter().filter_map(|r| r.parse_duration).reduce(|a, b| a + b);
+        if let Some(parse_duration) = parse_duration {
+            writeln!(self.out, "        Parse: {}", format_duration(parse_duration)).unwrap();
+        }
+        if results.len() > 1 || parse_duration.is_some() {
+            let total = results.iter().map(PartResult::elapsed).sum();
+            writeln!(self.out, "        Total: {}", format_duration(total)).unwrap();
+        }
+        self.days += 1;
+    }
+
+    fn finish(&mut self, report: &RunReport) {
+        if self.days > 1 {
+            writeln!(self.out, "Total: {}", format_duration(report.total_duration())).unwrap();
+        }
+    }
+}
+
+/// A compact table with one row per day, followed by the details of every
+/// part with a known answer that didn't match it. Used by `verify`.
+pub struct SummaryRenderer<W: Write> {
+    out: W,
+    header: bool,
+}
+
+impl<W: Write> SummaryRenderer<W> {
+    pub fn new(out: W) -> Self {
+        SummaryRenderer { out, header: false }
+    }
+}
+
+impl<W: Write> Renderer for SummaryRenderer<W> {
+    fn day(&mut self, results: &[PartResult]) {
+        if !self.header {
+            writeln!(self.out, "Day  Part 1  Part 2       Time").unwrap();
+            self.header = true;
+        }
+        let status = |part: usize| {
+            results
+                .iter()
+                .find(|r| r.part == part)
+                .map_or("-".to_string(), |r| r.status.to_string())
+        };
+        // Parts beyond the second are listed after the time
+        let mut extra: Vec<usize> = results.iter().map(|r| r.part).filter(|p| *p > 2).collect();
+        extra.sort_unstable();
+        extra.dedup();
+        let extra: String = extra
+            .into_iter()
+            .map(|p| format!("  Part {}: {}", p, status(p)))
+            .collect();
+        writeln!(
+            self.out,
+            " {:02}  {:>6}  {:>6}  {:>9}{}",
+            results.first().map_or(0, |r| r.day),
+            status(1),
+            status(2),
+            format_duration(results.iter().map(PartResult::elapsed).sum()),
+            extra,
+        )
+        .unwrap();
+    }
+
+    fn finish(&mut self, report: &RunReport) {
+        let verified = report
+            .results
+            .iter()
+            .filter(|r| r.expected.is_some() || r.status == TestStatus::Rejected);
+        let failed: Vec<&PartResult> = verified
+            .clone()
+            .filter(|r| r.status != TestStatus::Success)
+            .collect();
+        let verified = verified.count();
+        if !failed.is_empty() {
+            writeln!(self.out).unwrap();
+        }
+        for r in failed.iter() {
+            match &r.answer {
+                Some(answer) if r.status == TestStatus::Rejected => writeln!(
+                    self.out,
+                    "Day {:02}, Part {}: got {}, {}",
+                    r.day,
+                    r.part,
+                    answer,
+                    r.error.as_deref().unwrap_or_default()
+                ),
+                Some(answer) => writeln!(
+                    self.out,
+                    "Day {:02}, Part {}: expected {}, got {}",
+                    r.day,
+                    r.part,
+                    r.expected.as_deref().unwrap_or_default(),
+                    answer
+                ),
+                None => writeln!(self.out, "Day {:02}, Part {}: {}", r.day, r.part, r.output()),
+            }
+            .unwrap();
+        }
+        writeln!(
+            self.out,
+            "\nPassed: {}, Failed: {}, Unverified: {} ({})",
+            verified - failed.len(),
+            failed.len(),
+            report.results.len() - verified,
+            format_duration(report.total_duration()),
+        )
+        .unwrap();
+    }
+}
+
+/// Writes the whole report as a JSON array once the run is finished, with
+/// one object per day/part.
+pub struct JsonRenderer<W: Write> {
+    out: W,
+}
+
+impl<W: Write> JsonRenderer<W> {
+    pub fn new(out: W) -> Self {
+        JsonRenderer { out }
+    }
+}
+
+pub fn part_json(r: &PartResult) -> serde_json::Value {
+    serde_json::json!({
+        "day": r.day,
+        "part": r.part,
+        "input": r.input,
+        "answer": r.answer,
+        "expected": r.expected,
+        "status": r.status.name(),
+        "duration_ns": r.duration.as_nanos() as u64,
+        "parse_ns": r.parse_duration.map(|d| d.as_nanos() as u64),
+        "continued": r.continued,
+        "error": r.error,
+        "not_implemented": r.not_implemented,
+    })
+}
+
+/// The inverse of `part_json`.
+pub fn part_from_json(value: &serde_json::Value) -> Option<PartResult> {
+    let string = |key: &str| value.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Some(PartResult {
+        day: value.get("day")?.as_u64()? as usize,
+        part: value.get("part")?.as_u64()? as usize,
+        input: string("input").unwrap_or_else(|| DEFAULT_INPUT.to_string()),
+        answer: string("answer"),
+        expected: string("expected"),
+        status: TestStatus::from_name(value.get("status")?.as_str()?)?,
+        duration: Duration::from_nanos(value.get("duration_ns")?.as_u64()?),
+        parse_duration: value.get("parse_ns").and_then(|v| v.as_u64()).map(Duration::from_nanos),
+        continued: value.get("continued").and_then(|v| v.as_bool()).unwrap_or(false),
+        error: string("error"),
+        not_implemented: value.get("not_implemented").and_then(|v| v.as_bool()).unwrap_or(false),
+    })
+}
+
+impl<W: Write> Renderer for JsonRenderer<W> {
+    fn day(&mut self, _results: &[PartResult]) {}
+
+    fn finish(&mut self, report: &RunReport) {
+        let records: Vec<serde_json::Value> = report.results.iter().map(part_json).collect();
+        serde_json::to_writer_pretty(&mut self.out, &records).unwrap();
+        writeln!(self.out).unwrap();
+    }
+}
+
+/// Streams one CSV row per day/part, preceded by a header row.
+pub struct CsvRenderer<W: Write> {
+    out: W,
+    header: bool,
+}
+
+impl<W: Write> CsvRenderer<W> {
+    pub fn new(out: W) -> Self {
+        CsvRenderer { out, header: false }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl<W: Write> Renderer for CsvRenderer<W> {
+    fn day(&mut self, results: &[PartResult]) {
+        if !self.header {
+            writeln!(self.out, "day,part,input,status,answer,expected,duration_ns,parse_ns,continued,error").unwrap();
+            self.header = true;
+        }
+        for r in results {
+            writeln!(
+                self.out,
+                "{},{},{},{},{},{},{},{},{},{}",
+                r.day,
+                r.part,
+                csv_field(&r.input),
+                r.status.name(),
+                csv_field(r.answer.as_deref().unwrap_or_default()),
+                csv_field(r.expected.as_deref().unwrap_or_default()),
+                r.duration.as_nanos(),
+                r.parse_duration.map(|d| d.as_nanos().to_string()).unwrap_or_default(),
+                r.continued,
+                csv_field(r.error.as_deref().unwrap_or_default()),
+            )
+            .unwrap();
+        }
+    }
+
+    fn finish(&mut self, _report: &RunReport) {
+        self.out.flush().unwrap();
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{catch_panic, time, Answer, AoCDay, DayTimings, ErrorWrapper, Input, PreparedInput};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestStatus {
+    Unknown,
+    Failure,
+    Success,
+    Panicked,
+    TimedOut,
+    /// The answer was already guessed wrong, or is ruled out by guesses
+    /// which were too high or too low. See `GuessLog`.
+    Rejected,
+}
+
+impl TestStatus {
+    /// A stable name for the status, for machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Failure => "Failure",
+            Self::Success => "Success",
+            Self::Panicked => "Panicked",
+            Self::TimedOut => "TimedOut",
+            Self::Rejected => "Rejected",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Unknown" => Some(Self::Unknown),
+            "Failure" => Some(Self::Failure),
+            "Success" => Some(Self::Success),
+            "Panicked" => Some(Self::Panicked),
+            "TimedOut" => Some(Self::TimedOut),
+            "Rejected" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "?"),
+            Self::Failure => write!(f, "✗"),
+            Self::Success => write!(f, "✓"),
+            Self::Panicked => write!(f, "!"),
+            Self::TimedOut => write!(f, "⧖"),
+            Self::Rejected => write!(f, "✗"),
+        }
+    }
+}
+
+/// The outcome of running a single part of a day.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub day: usize,
+    pub part: usize,
+    /// Name of the input the part was run against.
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: TestStatus,
+    pub duration: Duration,
+    /// How long parsing the input took, for days which parse it separately
+    /// (see `ParsedDay`). Only set on the first part run against a parsed
+    /// input, so that it's counted once.
+    pub parse_duration: Option<Duration>,
+    /// Whether the part carried on from state left by part 1 (see
+    /// `StatefulDay`), so that its duration leaves out the work they share.
+    pub continued: bool,
+    /// Why the part failed to produce an answer, or why its answer was
+    /// rejected.
+    pub error: Option<String>,
+    /// Whether the part failed with `ErrorWrapper::NotImplemented`, as parts
+    /// a day leaves out do.
+    pub not_implemented: bool,
+}
+
+impl PartResult {
+    /// A result for `part` of the input called `input` before anything is
+    /// known about it: no answer, no time taken and an `Unknown` status.
+    pub fn new(day: usize, part: usize, input: &str) -> Self {
+        PartResult {
+            day,
+            part,
+            input: input.to_string(),
+            answer: None,
+            expected: None,
+            status: TestStatus::Unknown,
+            duration: Duration::ZERO,
+            parse_duration: None,
+            continued: false,
+            error: None,
+            not_implemented: false,
+        }
+    }
+
+    /// The answer, or the error message if the part failed to produce one.
+    pub fn output(&self) -> &str {
+        self.answer
+            .as_deref()
+            .or(self.error.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// How long the part took, including parsing the input if that was
+    /// done for it.
+    pub fn elapsed(&self) -> Duration {
+        self.duration + self.parse_duration.unwrap_or_default()
+    }
+}
+
+/// Every part result collected over a run, in execution order.
+#[derive(Clone, Debug, Default)]
+pub struct RunReport {
+    pub results: Vec<PartResult>,
+}
+
+impl RunReport {
+    pub fn new() -> Self {
+        RunReport { results: vec![] }
+    }
+
+    pub fn extend(&mut self, other: RunReport) {
+        self.results.extend(other.results);
+    }
+
+    pub fn day(&self, day: usize) -> impl Iterator<Item = &PartResult> {
+        self.results.iter().filter(move |r| r.day == day)
+    }
+
+    pub fn part(&self, day: usize, part: usize) -> Option<&PartResult> {
+        self.results.iter().find(|r| r.day == day && r.part == part)
+    }
+
+    /// Days present in the report, in the order they were first run.
+    pub fn days(&self) -> Vec<usize> {
+        let mut days: Vec<usize> = vec![];
+        for r in self.results.iter() {
+            if !days.contains(&r.day) {
+                days.push(r.day);
+            }
+        }
+        days
+    }
+
+    /// How long each part of `day` took, in the order they were run,
+    /// including the time spent parsing the input for them.
+    pub fn timings(&self, day: usize) -> DayTimings {
+        DayTimings {
+            day,
+            parts: self.day(day).map(|r| (r.part, r.elapsed())).collect(),
+        }
+    }
+
+    pub fn day_duration(&self, day: usize) -> Duration {
+        self.timings(day).total()
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.results.iter().map(PartResult::elapsed).sum()
+    }
+
+    pub fn count(&self, status: TestStatus) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+}
+
+/// Compares the value produced by a part against its expected answer (see
+/// `Answer::matches`), returning the status along with the answer or error
+/// as text.
+pub fn check_status(
+    expected: Option<&str>,
+    value: Result<Answer, ErrorWrapper>,
+) -> (TestStatus, String) {
+    match value {
+        Ok(val) => {
+            let status = match expected {
+                Some(v) => match val.matches(v) {
+                    true => TestStatus::Success,
+                    false => TestStatus::Failure,
+                },
+                None => TestStatus::Unknown,
+            };
+            (status, val.to_string())
+        }
+        Err(err) => (TestStatus::Failure, err.to_string()),
+    }
+}
+
+/// The answer `day` expects for `part` of the input registered under `name`.
+#[allow(clippy::borrowed_box)]
+pub fn expected_answer(day: &Box<dyn AoCDay>, part: usize, name: &str) -> Option<&'static str> {
+    match part {
+        1 => day.expected_for(name).0,
+        2 => day.expected_for(name).1,
+        _ => None,
+    }
+}
+
+// The result of running `part` of `day`, against `prepared` if the input
+// was parsed ahead of time.
+#[allow(clippy::borrowed_box)]
+fn run_prepared(day: &Box<dyn AoCDay>, part: usize, input: Input, mut prepared: Option<&mut dyn PreparedInput>) -> PartResult {
+    let expected = expected_answer(day, part, input.name);
+    let (value, duration) = match prepared.as_deref_mut() {
+        Some(prepared) => time(|| catch_panic(|| prepared.part(part))),
+        None => time(|| catch_panic(|| day.part(part, input.content))),
+    };
+    let not_implemented = matches!(value, Ok(Err(ErrorWrapper::NotImplemented)));
+    let (status, answer, error) = match value {
+        Ok(value) => {
+            let failed = value.is_err();
+            let (status, output) = check_status(expected, value);
+            match failed {
+                true => (status, None, Some(output)),
+                false => (status, Some(output), None),
+            }
+        }
+        Err(panic) => (TestStatus::Panicked, None, Some(panic.to_string())),
+    };
+    PartResult {
+        answer,
+        expected: expected.map(str::to_string),
+        status,
+        duration,
+        continued: prepared.is_some_and(|p| p.continued(part)),
+        error,
+        not_implemented,
+        ..PartResult::new(day.day(), part, input.name)
+    }
+}
+
+/// Runs and times one part of `day` without printing anything. A panic in
+/// the part is caught and reported as `TestStatus::Panicked`.
+#[allow(clippy::borrowed_box)]
+pub fn run_part<'a, I: Into<Input<'a>>>(day: &Box<dyn AoCDay>, part: usize, input: I) -> PartResult {
+    solve_day(day, Some(part), input).results.remove(0)
+}
+
+/// Runs the requested part (or every part) of `day` without printing
+/// anything, collecting the results into a report. Days which parse their
+/// input separately parse it once for all of the parts, and a failure to
+/// parse it fails each of them.
+#[allow(clippy::borrowed_box)]
+pub fn solve_day<'a, I: Into<Input<'a>>>(day: &Box<dyn AoCDay>, part: Option<usize>, input: I) -> RunReport {
+    let input = input.into();
+    let parts = match part {
+        Some(p) => vec![p],
+        None => (1..=day.parts()).collect(),
+    };
+    let (prepared, parse_duration) = time(|| catch_panic(|| day.prepare(input.content)));
+    let failed = |part: usize, status: TestStatus, error: &str| PartResult {
+        expected: expected_answer(day, part, input.name).map(str::to_string),
+        status,
+        error: Some(error.to_string()),
+        ..PartResult::new(day.day(), part, input.name)
+    };
+    let parsed = match &prepared {
+        Ok(Some(Ok(prepared))) => prepared.parsed(),
+        Ok(None) => false,
+        _ => true,
+    };
+    let mut results: Vec<PartResult> = match prepared {
+        Ok(None) => parts.iter().map(|p| run_prepared(day, *p, input, None)).collect(),
+        Ok(Some(Ok(mut prepared))) => parts
+            .iter()
+            .map(|p| run_prepared(day, *p, input, Some(prepared.as_mut())))
+            .collect(),
+        Ok(Some(Err(e))) => {
+            let error = e.to_string();
+            parts.iter().map(|p| failed(*p, TestStatus::Failure, &error)).collect()
+        }
+        Err(panic) => {
+            let error = panic.to_string();
+            parts.iter().map(|p| failed(*p, TestStatus::Panicked, &error)).collect()
+        }
+    };
+    if parsed {
+        if let Some(first) = results.first_mut() {
+            first.parse_duration = Some(parse_duration);
+        }
+    }
+    RunReport { results }
+}
@@ -0,0 +1,81 @@
+use crate::ErrorWrapper;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DayRange {
+    Single(usize),
+    // Inclusive; open ends are unbounded
+    Range(Option<usize>, Option<usize>),
+}
+
+/// A set of days written as a comma separated list of days and inclusive
+/// ranges, such as `1-5,9,12-`. Either end of a range may be left open.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection {
+    items: Vec<DayRange>,
+}
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection {
+            items: vec![DayRange::Range(None, None)],
+        }
+    }
+
+    pub fn contains(&self, day: usize) -> bool {
+        self.items.iter().any(|item| match *item {
+            DayRange::Single(d) => d == day,
+            DayRange::Range(start, end) => {
+                start.is_none_or(|s| day >= s) && end.is_none_or(|e| day <= e)
+            }
+        })
+    }
+
+    /// The day selected, if the selection is exactly one day.
+    pub fn single(&self) -> Option<usize> {
+        match self.items.as_slice() {
+            [DayRange::Single(d)] => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Days listed individually rather than as part of a range.
+    pub fn explicit_days(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                DayRange::Single(d) => Some(*d),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = ErrorWrapper;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_bound = |b: &str| -> Result<Option<usize>, ErrorWrapper> {
+            match b.trim() {
+                "" => Ok(None),
+                b => Ok(Some(b.parse::<usize>()?)),
+            }
+        };
+        let items = s
+            .split(',')
+            .map(str::trim)
+            .map(|item| match item.split_once('-') {
+                _ if item.is_empty() => Err(ErrorWrapper::ParseError("Empty day in selection".to_string())),
+                None => Ok(DayRange::Single(item.parse::<usize>()?)),
+                Some((start, end)) => {
+                    let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+                    match (start, end) {
+                        (Some(s), Some(e)) if s > e => Err(ErrorWrapper::ParseError(format!("Invalid day range {}", item))),
+                        _ => Ok(DayRange::Range(start, end)),
+                    }
+                }
+            })
+            .collect::<Result<Vec<DayRange>, ErrorWrapper>>()?;
+        Ok(DaySelection { items })
+    }
+}
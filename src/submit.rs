@@ -0,0 +1,107 @@
+use crate::{AoCClient, ErrorWrapper, Verdict};
+use std::time::Duration;
+
+/// What the puzzle site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Verdict(Verdict),
+    /// An answer was submitted too recently, with how long is left to wait
+    /// if the site said.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// A response which couldn't be made sense of, as its text.
+    Unrecognized(String),
+}
+
+impl std::fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Verdict(verdict) => write!(f, "{}", verdict),
+            SubmitOutcome::RateLimited(Some(wait)) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            SubmitOutcome::RateLimited(None) => write!(f, "answered too recently"),
+            SubmitOutcome::WrongLevel => write!(f, "already solved or not unlocked yet"),
+            SubmitOutcome::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+// The text of the `<article>` in `html` (or of all of it if there is none),
+// without tags and with whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// "You have 1m 30s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut secs = 0;
+    for part in wait.split_whitespace() {
+        let unit = part.chars().last()?;
+        let n: u64 = part[..part.len() - 1].parse().ok()?;
+        secs += match unit {
+            'h' => n * 3600,
+            'm' => n * 60,
+            's' => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Makes sense of the page the puzzle site responds to an answer with.
+pub fn parse_submit_response(html: &str) -> SubmitOutcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Verdict(Verdict::Correct)
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else if text.contains("That's not the right answer") {
+        SubmitOutcome::Verdict(match text {
+            t if t.contains("your answer is too high") => Verdict::TooHigh,
+            t if t.contains("your answer is too low") => Verdict::TooLow,
+            _ => Verdict::Wrong,
+        })
+    } else {
+        SubmitOutcome::Unrecognized(text)
+    }
+}
+
+impl AoCClient {
+    /// Submits `answer` to `part` of `day`.
+    pub fn submit(&self, day: usize, part: usize, answer: &str) -> Result<SubmitOutcome, ErrorWrapper> {
+        self.session()?;
+        self.ensure_unlocked(day)?;
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let (status, body) = self.request(&format!("/{}/day/{}/answer", self.year, day), Some(&form))?;
+        match status {
+            200 => Ok(parse_submit_response(&body)),
+            _ => Err(ErrorWrapper::Simple(format!(
+                "Submitting failed with status {}: {}",
+                status,
+                article_text(&body)
+            ))),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+/// Runs `func`, returning its result alongside how long it took.
+pub fn time<T, F: FnOnce() -> T>(func: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = func();
+    (result, start.elapsed())
+}
+
+/// Formats a duration with the largest unit that keeps the value above 1,
+/// e.g. `512ns`, `12.34µs`, `1.50ms` or `2.03s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1_000.0)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1_000_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Wall-clock time spent on each executed part of a day, as collected by
+/// `RunReport::timings`.
+#[derive(Clone, Debug, Default)]
+pub struct DayTimings {
+    pub day: usize,
+    pub parts: Vec<(usize, Duration)>,
+}
+
+impl DayTimings {
+    pub fn new(day: usize) -> Self {
+        DayTimings { day, parts: vec![] }
+    }
+
+    pub fn part(&self, part: usize) -> Option<Duration> {
+        self.parts.iter().find(|(p, _)| *p == part).map(|(_, d)| *d)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parts.iter().map(|(_, d)| *d).sum()
+    }
+}
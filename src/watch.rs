@@ -0,0 +1,138 @@
+use crate::{solve_days_parallel, AoCDay, ErrorWrapper, ExitStatus, Input, RunOptions, RunReport, TextRenderer, DEFAULT_INPUT};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+#[derive(Clone, Debug)]
+pub struct WatchOptions {
+    /// The input file re-read and re-run whenever it changes.
+    pub input: PathBuf,
+    /// A source directory which, when any `.rs` file in it changes, triggers
+    /// a rebuild followed by restarting the current command.
+    pub sources: Option<PathBuf>,
+    pub interval: Duration,
+}
+
+/// The most recent modification time of `path`, or of anything beneath it
+/// if it is a directory. With an `extension`, only files with that extension
+/// are considered.
+pub fn latest_modified(path: &Path, extension: Option<&str>) -> Option<SystemTime> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return match extension {
+            Some(ext) if path.extension() != Some(ext.as_ref()) => None,
+            _ => metadata.modified().ok(),
+        };
+    }
+    std::fs::read_dir(path)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter_map(|e| latest_modified(&e.path(), extension))
+        .chain(metadata.modified().ok().filter(|_| extension.is_none()))
+        .max()
+}
+
+/// The file for the input of `day` called `name`, as laid out for
+/// `generate_get_inputs`. Relative to `$CARGO_MANIFEST_DIR` when run through
+/// cargo.
+pub fn default_input_path(day: usize, name: &str) -> PathBuf {
+    let mut path = std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    path.push("src");
+    path.push("input");
+    match name {
+        DEFAULT_INPUT => path.push(format!("day_{:02}.txt", day)),
+        _ => path.push(format!("day_{:02}.{}.txt", day, name)),
+    }
+    path
+}
+
+// Rebuilds the project and replaces this process with the new `exe`, run
+// with the same arguments. Only returns if either step fails.
+fn rebuild_and_restart(exe: &Path) -> ErrorWrapper {
+    let mut build = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    build.arg("build");
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    match build.status() {
+        Ok(s) if s.success() => {}
+        Ok(s) => return ErrorWrapper::Simple(format!("cargo build exited with {}", s)),
+        Err(e) => return e.into(),
+    }
+    let mut restart = Command::new(exe);
+    restart.args(std::env::args_os().skip(1));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        restart.exec().into()
+    }
+    #[cfg(not(unix))]
+    match restart.status() {
+        Ok(s) => std::process::exit(s.code().unwrap_or(1)),
+        Err(e) => e.into(),
+    }
+}
+
+/// Re-runs `day` every time `watch.input` changes, clearing the screen and
+/// showing how each answer changed since the previous run. Only returns if
+/// the input can't be read. Parts still running past their timeout are
+/// waited on rather than handed to `options.on_stuck`.
+#[allow(clippy::borrowed_box)]
+pub fn watch_day(day: &Box<dyn AoCDay>, options: &RunOptions, watch: &WatchOptions) -> ExitStatus {
+    let options = RunOptions { on_stuck: None, ..options.clone() };
+    let mut input_modified = None;
+    let mut sources_modified = watch.sources.as_deref().and_then(|s| latest_modified(s, Some("rs")));
+    let mut previous: Option<RunReport> = None;
+    let mut build_error: Option<ErrorWrapper> = None;
+    // Resolved up front, as on some platforms it stops resolving once the
+    // binary has been replaced by a rebuild.
+    let exe = std::env::current_exe();
+
+    loop {
+        if let Some(sources) = watch.sources.as_deref() {
+            let modified = latest_modified(sources, Some("rs"));
+            if modified != sources_modified {
+                sources_modified = modified;
+                println!("Sources changed, rebuilding...");
+                build_error = Some(match exe.as_deref() {
+                    Ok(exe) => rebuild_and_restart(exe),
+                    Err(e) => ErrorWrapper::Simple(format!("Unable to locate executable: {}", e)),
+                });
+                // Force a refresh so the build error is shown
+                input_modified = None;
+            }
+        }
+
+        let modified = latest_modified(&watch.input, None);
+        if modified.is_some() && modified != input_modified {
+            input_modified = modified;
+            let input = match std::fs::read_to_string(&watch.input) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Unable to read input: {}", e);
+                    return ExitStatus::Missing;
+                }
+            };
+
+            print!("\x1b[2J\x1b[H");
+            println!("Watching {} (Ctrl-C to stop)\n", watch.input.display());
+            if let Some(e) = build_error.take() {
+                println!("Rebuild failed: {}\n", e);
+            }
+            let mut renderer = TextRenderer::stdout();
+            let report = solve_days_parallel(&[(day, Input::from(&input))], &options, &mut renderer);
+            if let Some(previous) = previous.as_ref() {
+                for r in report.results.iter() {
+                    let before = previous.part(r.day, r.part).map(|p| p.output());
+                    if before.is_some_and(|b| b != r.output()) {
+                        println!("Part {} changed from {}", r.part, before.unwrap_or_default());
+                    }
+                }
+            }
+            previous = Some(report);
+        }
+        std::thread::sleep(watch.interval);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{expected_answer, format_duration, part_from_json, AoCDay, Input, PartResult, TestStatus};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The result reported for a part which didn't finish within `timeout`.
+#[allow(clippy::borrowed_box)]
+pub fn timed_out(day: &Box<dyn AoCDay>, part: usize, input_name: &str, timeout: Duration) -> PartResult {
+    PartResult {
+        expected: expected_answer(day, part, input_name).map(str::to_string),
+        status: TestStatus::TimedOut,
+        duration: timeout,
+        error: Some(format!("timed out after {}", format_duration(timeout))),
+        ..PartResult::new(day.day(), part, input_name)
+    }
+}
+
+/// Runs one part in a child process by invoking `exe run --day D --part P
+/// --input NAME --stdin --format json`, where `exe` is a binary built around
+/// `AoCProject::run`, feeding it `input` over stdin. The child is killed if it
+/// is still running after `timeout`. Crashes which would take down the whole
+/// process in-process, such as stack overflows, are reported as panics.
+#[allow(clippy::borrowed_box)]
+pub fn run_part_isolated(
+    exe: &Path,
+    day: &Box<dyn AoCDay>,
+    part: usize,
+    input: Input,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let failed = |status: TestStatus, error: String, duration: Duration| PartResult {
+        status,
+        duration,
+        error: Some(error),
+        ..timed_out(day, part, input.name, Duration::ZERO)
+    };
+
+    let start = Instant::now();
+    let mut child = match Command::new(exe)
+        .args(["run", "--day", &day.day().to_string(), "--part", &part.to_string()])
+        .args(["--input", input.name, "--stdin", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return failed(TestStatus::Failure, format!("unable to start child process: {}", e), Duration::ZERO),
+    };
+
+    // Write the input and collect the output on other threads so a full pipe
+    // can't stall the child while we wait on it.
+    let mut stdin = child.stdin.take().unwrap();
+    let content = input.content.to_string();
+    std::thread::spawn(move || stdin.write_all(content.as_bytes()));
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = std::thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {}
+            Err(e) => return failed(TestStatus::Failure, format!("unable to wait on child process: {}", e), start.elapsed()),
+        }
+        if let Some(timeout) = timeout {
+            if start.elapsed() >= timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return timed_out(day, part, input.name, timeout);
+            }
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    let output = reader.join().ok().and_then(Result::ok).unwrap_or_default();
+    let result = serde_json::from_str::<serde_json::Value>(&output)
+        .ok()
+        .and_then(|v| v.get(0).and_then(part_from_json));
+    match result {
+        Some(result) => result,
+        None => failed(
+            TestStatus::Panicked,
+            format!("child process exited with {}", status),
+            start.elapsed(),
+        ),
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_core::{block_char, check_status, Answer, ErrorWrapper, TestStatus};
+
+#[test]
+fn answer_from() {
+    const DESC: &str = "Should convert numbers to integers and multi-line text to art";
+    assert_eq!(Answer::from(42u8), Answer::Integer(42), "{}", DESC);
+    assert_eq!(Answer::from(-7i64), Answer::Integer(-7), "{}", DESC);
+    assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()), "{}", DESC);
+    assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()), "{}", DESC);
+    assert_eq!(Answer::from("abc\n"), Answer::Text("abc\n".to_string()), "{}", DESC);
+    assert_eq!(Answer::from("#.\n.#"), Answer::Art("#.\n.#".to_string()), "{}", DESC);
+    assert_eq!(Answer::from(12345usize).to_string(), "12345", "{}", DESC);
+}
+
+#[test]
+fn answer_from_str() {
+    const DESC: &str = "Should read integers back as they're displayed, and anything else as text";
+    assert_eq!("-42".parse::<Answer>(), Ok(Answer::Integer(-42)), "{}", DESC);
+    assert_eq!("042".parse::<Answer>(), Ok(Answer::Text("042".to_string())), "{}", DESC);
+    assert_eq!("+1".parse::<Answer>(), Ok(Answer::Text("+1".to_string())), "{}", DESC);
+    assert_eq!("#.\n.#".parse::<Answer>(), Ok(Answer::Art("#.\n.#".to_string())), "{}", DESC);
+}
+
+#[test]
+fn answer_matches() {
+    const DESC: &str = "Should compare integers by value and art line by line";
+    assert!(Answer::from(42).matches("042"), "{}", DESC);
+    assert!(Answer::from(42).matches(" 42\n"), "{}", DESC);
+    assert!(!Answer::from(42).matches("42.0"), "{}", DESC);
+    assert!(Answer::from("abc").matches("abc\n"), "{}", DESC);
+    assert!(!Answer::from("abc").matches("ABC"), "{}", DESC);
+    assert!(Answer::from("#. \n.#").matches("\n#.\n.#\n"), "{}", DESC);
+    assert!(!Answer::from("#.\n.#").matches("#.\n##"), "{}", DESC);
+}
+
+#[test]
+fn answer_pixels() {
+    const DESC: &str = "Should draw lit pixels as blocks, trimming each line";
+    let art = Answer::pixels(&[vec![true, false, false], vec![false, true, false]]);
+    let block = block_char();
+    assert_eq!(art, Answer::Art(format!("{}\n {}", block, block)), "{}", DESC);
+}
+
+#[test]
+fn check_status_numeric() {
+    const DESC: &str = "Should check typed answers against expected text";
+    assert_eq!(check_status(Some("007"), Ok(7.into())), (TestStatus::Success, "7".to_string()), "{}", DESC);
+    assert_eq!(check_status(Some("8"), Ok(7.into())).0, TestStatus::Failure, "{}", DESC);
+    assert_eq!(check_status(None, Ok(7.into())).0, TestStatus::Unknown, "{}", DESC);
+    assert_eq!(
+        check_status(Some("7"), Err(ErrorWrapper::NotImplemented)),
+        (TestStatus::Failure, "NotImplemented".to_string()),
+        "{}",
+        DESC
+    );
+}
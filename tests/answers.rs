@@ -0,0 +1,167 @@
+use aoc_core::{solve_day, Answer, Answers, AoCDay, ErrorWrapper, Input, PartResult, RecordedAnswer, TestStatus, DEFAULT_INPUT};
+
+const FILE: &str = "1: 1234
+2: two\\nlines
+
+[example1]
+1: 42
+";
+
+fn result(answer: &str, expected: Option<&str>, status: TestStatus) -> PartResult {
+    PartResult {
+        answer: Some(answer.to_string()),
+        expected: expected.map(str::to_string),
+        status,
+        ..PartResult::new(5, 1, "example1")
+    }
+}
+
+#[test]
+fn answers_file_round_trip() {
+    const DESC: &str = "Should parse and format answers files, keyed by input";
+    let mut answers = Answers::new();
+    answers.parse_day(5, FILE).unwrap();
+    assert_eq!(answers.get(5, 1, DEFAULT_INPUT), Some("1234"), "{}", DESC);
+    assert_eq!(answers.get(5, 2, DEFAULT_INPUT), Some("two\nlines"), "{}", DESC);
+    assert_eq!(answers.get(5, 1, "example1"), Some("42"), "{}", DESC);
+    assert_eq!(answers.get(5, 2, "example1"), None, "{}", DESC);
+    assert!(answers.has_day(5) && !answers.has_day(6), "{}", DESC);
+    assert_eq!(answers.format_day(5), FILE, "{}", DESC);
+    assert!(answers.parse_day(6, "1 1234").is_err(), "{}", DESC);
+}
+
+#[test]
+fn answers_load_and_save() {
+    const DESC: &str = "Should save answers to and load them from a directory";
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("aoc_core_answers_{}", std::process::id()));
+    assert!(Answers::load(&dir).unwrap().is_empty(), "{}", DESC);
+
+    let mut answers = Answers::new();
+    answers.insert(3, 2, DEFAULT_INPUT, "17".to_string());
+    answers.save_day(&dir, 3).unwrap();
+    assert_eq!(std::fs::read_to_string(dir.join("day_03.txt")).unwrap(), "2: 17\n", "{}", DESC);
+    assert_eq!(Answers::load(&dir).unwrap(), answers, "{}", DESC);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn recorded_answers_checked() {
+    const DESC: &str = "Should check results against recorded answers before expected()";
+    let mut answers = Answers::new();
+    answers.parse_day(5, FILE).unwrap();
+    let example = Input::named("example1", "abc");
+
+    let mut unknown = result("42", None, TestStatus::Unknown);
+    answers.check(&mut unknown, &example);
+    assert_eq!(unknown.status, TestStatus::Success, "{}", DESC);
+    assert_eq!(unknown.expected.as_deref(), Some("42"), "{}", DESC);
+
+    let mut overridden = result("41", Some("41"), TestStatus::Success);
+    answers.check(&mut overridden, &example);
+    assert_eq!(overridden.status, TestStatus::Failure, "{}", DESC);
+
+    let mut unrecorded = result("41", Some("41"), TestStatus::Success);
+    unrecorded.part = 2;
+    answers.check(&mut unrecorded, &example);
+    assert_eq!(unrecorded.status, TestStatus::Success, "{}", DESC);
+    assert_eq!(unrecorded.expected.as_deref(), Some("41"), "{}", DESC);
+}
+
+#[test]
+fn recorded_answers_compared_by_value() {
+    const DESC: &str = "Should compare integer answers to recorded ones by value";
+    let mut answers = Answers::new();
+    answers.parse_day(5, "[example1]\n1: 042\n2: 007x\n").unwrap();
+    let example = Input::named("example1", "abc");
+
+    let mut integer = result("42", None, TestStatus::Unknown);
+    answers.check(&mut integer, &example);
+    assert_eq!(integer.status, TestStatus::Success, "{}", DESC);
+
+    let mut text = result("7x", None, TestStatus::Unknown);
+    text.part = 2;
+    answers.check(&mut text, &example);
+    assert_eq!(text.status, TestStatus::Failure, "{}", DESC);
+}
+
+#[test]
+fn answers_keyed_by_input_hash() {
+    const DESC: &str = "Should tell apart answers for different contents of an input";
+    let alice = Input::from("1\n2\n3\n");
+    let bob = Input::from("4\n5\n6\n");
+    let carol = Input::from("7\n8\n9\n");
+    let mut answers = Answers::new();
+    answers.parse_day(1, "1: 6\n").unwrap();
+    assert_eq!(answers.lookup(1, 1, &carol), RecordedAnswer::Known("6"), "{}", DESC);
+
+    answers.insert_for(1, 1, &alice, "6".to_string());
+    answers.insert_for(1, 1, &bob, "15".to_string());
+    assert_eq!(answers.get(1, 1, DEFAULT_INPUT), None, "{}", DESC);
+    assert_eq!(answers.lookup(1, 1, &alice), RecordedAnswer::Known("6"), "{}", DESC);
+    assert_eq!(answers.lookup(1, 1, &bob), RecordedAnswer::Known("15"), "{}", DESC);
+    assert_eq!(answers.lookup(1, 1, &carol), RecordedAnswer::UnknownInput, "{}", DESC);
+
+    let renamed = Input::named("renamed", "4\n5\n6\n");
+    assert_eq!(answers.lookup(1, 1, &renamed), RecordedAnswer::Known("15"), "{}", DESC);
+    assert_eq!(answers.lookup(1, 1, &Input::named("example1", "x")), RecordedAnswer::Unrecorded, "{}", DESC);
+
+    let file = answers.format_day(1);
+    assert!(file.contains(&format!("[default {}]\n1: 6\n", alice.hash())), "{}", DESC);
+    assert!(file.contains(&format!("[default {}]\n1: 15\n", bob.hash())), "{}", DESC);
+    let mut parsed = Answers::new();
+    parsed.parse_day(1, &file).unwrap();
+    assert_eq!(parsed, answers, "{}", DESC);
+
+    let mut result = PartResult {
+        answer: Some("24".to_string()),
+        expected: Some("6".to_string()),
+        status: TestStatus::Failure,
+        ..PartResult::new(1, 1, DEFAULT_INPUT)
+    };
+    answers.check(&mut result, &carol);
+    assert_eq!(result.status, TestStatus::Unknown, "{}", DESC);
+    assert_eq!(result.expected, None, "{}", DESC);
+}
+
+struct Count;
+impl AoCDay for Count {
+    fn day(&self) -> usize {
+        5
+    }
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.len().into())
+    }
+    fn part2(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok((input.len() + 2).into())
+    }
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        match name {
+            "example1" => (Some("3"), None),
+            _ => (None, None),
+        }
+    }
+}
+
+#[test]
+fn example_answers_survive_hashed_main_input() {
+    const DESC: &str = "Should keep checking examples by name once the main input has hashed answers";
+    let day: Box<dyn AoCDay> = Box::new(Count);
+    let example = Input::named("example1", "abc");
+    let mut answers = Answers::new();
+    answers.parse_day(5, "[example1]\n2: 5\n").unwrap();
+    answers.insert_for(5, 1, &Input::named(DEFAULT_INPUT, "main"), "4".to_string());
+    answers.insert_for(5, 2, &Input::named(DEFAULT_INPUT, "main"), "6".to_string());
+
+    let mut report = solve_day(&day, None, example);
+    for r in report.results.iter_mut() {
+        answers.check(r, &example);
+    }
+    let part1 = report.part(5, 1).unwrap();
+    assert_eq!(part1.status, TestStatus::Success, "{}", DESC);
+    assert_eq!(part1.expected.as_deref(), Some("3"), "{}", DESC);
+    let part2 = report.part(5, 2).unwrap();
+    assert_eq!(part2.status, TestStatus::Success, "{}", DESC);
+    assert_eq!(part2.expected.as_deref(), Some("5"), "{}", DESC);
+}
@@ -0,0 +1,69 @@
+use aoc_core::{bench_part, Answer, AoCDay, BenchLimit, BenchOptions, BenchStats, ErrorWrapper, Stateful, StatefulDay};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn bench_stats_simple() {
+    const DESC: &str = "Should compute order statistics over the samples";
+    let samples: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+    let stats = BenchStats::from_samples(&samples).unwrap();
+    assert_eq!(stats.samples, 10, "{}", DESC);
+    assert_eq!(stats.outliers, 0, "{}", DESC);
+    assert_eq!(stats.min, Duration::from_millis(1), "{}", DESC);
+    assert_eq!(stats.max, Duration::from_millis(10), "{}", DESC);
+    assert_eq!(stats.median, Duration::from_millis(5), "{}", DESC);
+    assert_eq!(stats.p95, Duration::from_millis(10), "{}", DESC);
+    assert_eq!(stats.mean, Duration::from_micros(5_500), "{}", DESC);
+}
+
+#[test]
+fn bench_stats_outliers() {
+    const DESC: &str = "Should discard samples outside of 1.5 × IQR";
+    let mut samples: Vec<Duration> = vec![Duration::from_millis(10); 20];
+    samples.push(Duration::from_secs(5));
+    let stats = BenchStats::from_samples(&samples).unwrap();
+    assert_eq!(stats.outliers, 1, "{}", DESC);
+    assert_eq!(stats.max, Duration::from_millis(10), "{}", DESC);
+    assert_eq!(stats.stddev, Duration::ZERO, "{}", DESC);
+}
+
+#[test]
+fn bench_stats_empty() {
+    const DESC: &str = "Should refuse to summarize zero samples";
+    assert!(BenchStats::from_samples(&[]).is_none(), "{}", DESC);
+}
+
+struct Climb {
+    climbs: Arc<AtomicUsize>,
+}
+impl StatefulDay for Climb {
+    type State = usize;
+
+    fn day(&self) -> usize {
+        7
+    }
+    fn part1(&self, input: &str) -> Result<(Answer, usize), ErrorWrapper> {
+        self.climbs.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(20));
+        Ok((input.len().into(), input.len()))
+    }
+    fn part2(&self, _input: &str, height: usize) -> Result<Answer, ErrorWrapper> {
+        Ok((height * 2).into())
+    }
+}
+
+#[test]
+fn bench_continued_part() {
+    const DESC: &str = "Should run part 1 before each run of part 2 without timing it";
+    let climbs = Arc::new(AtomicUsize::new(0));
+    let day: Box<dyn AoCDay> = Box::new(Stateful(Climb { climbs: climbs.clone() }));
+    let options = BenchOptions {
+        warmup: 1,
+        limit: BenchLimit::Iterations(3),
+    };
+    let stats = bench_part(&day, 2, "abc", &options).unwrap();
+    assert_eq!(climbs.load(Ordering::SeqCst), 4, "{}", DESC);
+    assert_eq!(stats.samples, 3, "{}", DESC);
+    assert!(stats.max < Duration::from_millis(20), "{}", DESC);
+}
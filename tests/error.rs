@@ -0,0 +1,14 @@
+use aoc_core::ErrorWrapper;
+use std::error::Error;
+
+#[test]
+fn wrapped_keeps_source() {
+    const DESC: &str = "Should keep boxed errors as their source, whether or not they're Send";
+    let local: Box<dyn Error> = "12x".parse::<u8>().unwrap_err().into();
+    let sendable: Box<dyn Error + Send + Sync> = "12x".parse::<u8>().unwrap_err().into();
+    for e in [ErrorWrapper::from(local), ErrorWrapper::from(sendable)] {
+        assert!(matches!(e, ErrorWrapper::Wrapped(_)), "{}", DESC);
+        let source = e.source().unwrap();
+        assert!(source.downcast_ref::<std::num::ParseIntError>().is_some(), "{}", DESC);
+    }
+}
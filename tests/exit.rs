@@ -0,0 +1,57 @@
+use aoc_core::{ExitStatus, PartResult, RunReport, TestStatus, DEFAULT_INPUT};
+
+fn result(status: TestStatus, error: Option<&str>) -> PartResult {
+    PartResult {
+        answer: error.is_none().then(|| "1".to_string()),
+        expected: Some("1".to_string()),
+        status,
+        error: error.map(str::to_string),
+        ..PartResult::new(1, 1, DEFAULT_INPUT)
+    }
+}
+
+#[test]
+fn exit_status_from_report() {
+    const DESC: &str = "Should report the worst outcome in the run";
+    let mut report = RunReport::new();
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::Passed, "{}", DESC);
+
+    report.results.push(result(TestStatus::Success, None));
+    report.results.push(result(TestStatus::Unknown, None));
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::Passed, "{}", DESC);
+
+    report.results.push(result(TestStatus::Failure, None));
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::WrongAnswer, "{}", DESC);
+
+    report.results.push(result(TestStatus::Failure, Some("NotImplemented")));
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::Errored, "{}", DESC);
+}
+
+#[test]
+fn exit_status_codes() {
+    const DESC: &str = "Should use a distinct code for each outcome";
+    let statuses = [
+        ExitStatus::Passed,
+        ExitStatus::Missing,
+        ExitStatus::WrongAnswer,
+        ExitStatus::Errored,
+        ExitStatus::Usage,
+    ];
+    let mut codes: Vec<i32> = statuses.iter().map(|s| s.code()).collect();
+    assert_eq!(codes[0], 0, "{}", DESC);
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), statuses.len(), "{}", DESC);
+}
+
+#[test]
+fn exit_status_from_verified() {
+    const DESC: &str = "Should ignore parts without a known answer";
+    let mut unverified = result(TestStatus::Panicked, Some("boom"));
+    unverified.expected = None;
+    let mut report = RunReport::new();
+    report.results.push(result(TestStatus::Success, None));
+    report.results.push(unverified);
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::Errored, "{}", DESC);
+    assert_eq!(ExitStatus::from_verified(&report), ExitStatus::Passed, "{}", DESC);
+}
@@ -0,0 +1,66 @@
+use aoc_core::{ExitStatus, GuessLog, Input, PartResult, RunReport, TestStatus, Verdict, DEFAULT_INPUT};
+
+fn result(answer: &str) -> PartResult {
+    PartResult {
+        answer: Some(answer.to_string()),
+        ..PartResult::new(3, 1, DEFAULT_INPUT)
+    }
+}
+
+#[test]
+fn guesses_bound_answers() {
+    const DESC: &str = "Should reject answers already guessed or outside the known bounds";
+    let input = Input::from("input");
+    let hash = input.hash();
+    let log = GuessLog::parse(&format!(
+        "3 1 default {0} too-high 500\n3 1 default {0} too-high 900\n3 1 default {0} too-low 100\n3 1 default {0} wrong 300\n3 2 default {0} wrong two\\nlines\n",
+        hash
+    ))
+    .unwrap();
+    assert_eq!(log.guesses.len(), 5, "{}", DESC);
+    assert_eq!(log.guesses[4].answer, "two\nlines", "{}", DESC);
+
+    assert_eq!(log.rejection(3, 1, &input, "300").as_deref(), Some("already guessed, wrong"), "{}", DESC);
+    assert_eq!(log.rejection(3, 1, &input, "700").as_deref(), Some("must be lower than 500"), "{}", DESC);
+    assert_eq!(log.rejection(3, 1, &input, "50").as_deref(), Some("must be higher than 100"), "{}", DESC);
+    assert_eq!(log.rejection(3, 1, &input, "250"), None, "{}", DESC);
+    assert_eq!(log.rejection(3, 1, &input, "abc"), None, "{}", DESC);
+    assert_eq!(log.rejection(3, 2, &input, "two\nlines").as_deref(), Some("already guessed, wrong"), "{}", DESC);
+    assert_eq!(log.rejection(3, 1, &Input::from("other input"), "700"), None, "{}", DESC);
+
+    let mut rejected = result("700");
+    log.check(&mut rejected, &input);
+    assert_eq!(rejected.status, TestStatus::Rejected, "{}", DESC);
+    assert_eq!(rejected.error.as_deref(), Some("must be lower than 500"), "{}", DESC);
+    let report = RunReport { results: vec![rejected] };
+    assert_eq!(ExitStatus::from_report(&report), ExitStatus::WrongAnswer, "{}", DESC);
+    assert_eq!(ExitStatus::from_verified(&report), ExitStatus::WrongAnswer, "{}", DESC);
+
+    let mut plausible = result("250");
+    log.check(&mut plausible, &input);
+    assert_eq!(plausible.status, TestStatus::Unknown, "{}", DESC);
+}
+
+#[test]
+fn guesses_recorded() {
+    const DESC: &str = "Should append guesses to the log and use a correct one as the expected answer";
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("aoc_core_guesses_{}", std::process::id()));
+    let input = Input::from("input");
+
+    let mut log = GuessLog::load(&dir).unwrap();
+    assert!(log.guesses.is_empty(), "{}", DESC);
+    log.record(&dir, 3, 1, &input, Verdict::TooLow, "100").unwrap();
+    log.record(&dir, 3, 1, &input, Verdict::Correct, "250").unwrap();
+    assert_eq!(GuessLog::load(&dir).unwrap(), log, "{}", DESC);
+
+    let mut wrong = result("300");
+    log.check(&mut wrong, &input);
+    assert_eq!(wrong.status, TestStatus::Failure, "{}", DESC);
+    assert_eq!(wrong.expected.as_deref(), Some("250"), "{}", DESC);
+    let mut right = result("250");
+    log.check(&mut right, &input);
+    assert_eq!(right.status, TestStatus::Success, "{}", DESC);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
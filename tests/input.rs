@@ -0,0 +1,122 @@
+#![cfg(feature = "net")]
+use aoc_core::{unlock_time, AoCClient, InputSource, NamedInput, WithDownloads, DEFAULT_INPUT};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+use std::time::{Duration, UNIX_EPOCH};
+
+// Serves a single request with `status` and `body`, returning the request.
+fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = vec![];
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+            if n == 0 {
+                break;
+            }
+        }
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+        .unwrap();
+        String::from_utf8(request).unwrap()
+    });
+    (url, handle)
+}
+
+fn client(url: &str, name: &str) -> AoCClient {
+    let mut cache = std::env::temp_dir();
+    cache.push(format!("aoc_core_input_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache);
+    AoCClient {
+        base_url: url.to_string(),
+        session: Some("abc123".to_string()),
+        cache_dir: Some(cache),
+        ..AoCClient::new(2015, "tests (test@example.com)".to_string())
+    }
+}
+
+fn cleanup(client: &AoCClient) {
+    std::fs::remove_dir_all(client.cache_dir.as_ref().unwrap()).unwrap();
+}
+
+#[test]
+fn input_downloaded_and_cached() {
+    const DESC: &str = "Should download an input with the session token once and then use the cache";
+    let (url, server) = serve_once("200 OK", "1\n2\n3\n");
+    let client = client(&url, "download");
+    assert_eq!(client.get_input(1).unwrap(), "1\n2\n3\n", "{}", DESC);
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2015/day/1/input HTTP/1.1\r\n"), "{}", DESC);
+    assert!(request.contains("Cookie: session=abc123\r\n"), "{}", DESC);
+    assert!(request.contains("User-Agent: tests (test@example.com)\r\n"), "{}", DESC);
+
+    // The server is gone, so this has to come from the cache
+    assert_eq!(client.get_input(1).unwrap(), "1\n2\n3\n", "{}", DESC);
+    let cached = client.cache_path(1).unwrap();
+    assert!(cached.ends_with(PathBuf::from("2015/1/input.txt")), "{}", DESC);
+
+    let embedded: HashMap<usize, &str> = [(2, "embedded")].into_iter().collect();
+    let inputs = WithDownloads { inputs: &embedded, client: client.clone() };
+    assert_eq!(inputs.get(1, DEFAULT_INPUT).as_deref(), Some("1\n2\n3\n"), "{}", DESC);
+    assert_eq!(inputs.get(2, DEFAULT_INPUT).as_deref(), Some("embedded"), "{}", DESC);
+    assert_eq!(inputs.get(1, "example1"), None, "{}", DESC);
+    assert_eq!(inputs.names(1), vec![DEFAULT_INPUT], "{}", DESC);
+    cleanup(&client);
+}
+
+#[test]
+fn input_cached_beside_examples() {
+    const DESC: &str = "Should fall back to the cached main input of a day with only examples embedded";
+    let client = AoCClient { session: None, ..client("http://127.0.0.1:9", "examples") };
+    let cached = client.cache_path(5).unwrap();
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, "cached").unwrap();
+
+    let examples = vec![NamedInput { day: 5, name: "example1", content: "example" }];
+    let inputs = WithDownloads { inputs: &examples, client: client.clone() };
+    assert_eq!(inputs.get(5, DEFAULT_INPUT).as_deref(), Some("cached"), "{}", DESC);
+    assert_eq!(inputs.get(5, "example1").as_deref(), Some("example"), "{}", DESC);
+    assert_eq!(inputs.names(5), vec![DEFAULT_INPUT, "example1"], "{}", DESC);
+    assert!(inputs.names(6).is_empty(), "{}", DESC);
+    cleanup(&client);
+}
+
+#[test]
+fn input_download_failures() {
+    const DESC: &str = "Should report rejected sessions, missing tokens and locked days";
+    let (url, server) = serve_once("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+    let client = client(&url, "failures");
+    let error = client.get_input(3).unwrap_err().to_string();
+    assert!(error.contains("session token was rejected"), "{}", DESC);
+    server.join().unwrap();
+    assert!(client.cached_input(3).is_none(), "{}", DESC);
+
+    let anonymous = AoCClient { session: None, ..client.clone() };
+    assert!(anonymous.get_input(3).unwrap_err().to_string().contains("No session token"), "{}", DESC);
+
+    let future = AoCClient { year: 9999, ..client.clone() };
+    assert!(future.get_input(1).unwrap_err().to_string().contains("unlocks in"), "{}", DESC);
+    assert!(client.get_input(26).is_err(), "{}", DESC);
+    let inputs = WithDownloads { inputs: &HashMap::<usize, &str>::new(), client: future };
+    assert!(inputs.names(1).is_empty(), "{}", DESC);
+}
+
+#[test]
+fn unlock_times() {
+    const DESC: &str = "Should unlock puzzles at midnight US Eastern time";
+    assert_eq!(unlock_time(2020, 1), UNIX_EPOCH + Duration::from_secs(1606798800), "{}", DESC);
+    assert_eq!(unlock_time(2023, 25), UNIX_EPOCH + Duration::from_secs(1703480400), "{}", DESC);
+}
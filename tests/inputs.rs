@@ -0,0 +1,69 @@
+use aoc_core::{solve_day, Answer, AoCDay, ErrorWrapper, Input, InputSource, NamedInput, TestStatus, DEFAULT_INPUT};
+use std::collections::HashMap;
+
+struct Example;
+impl AoCDay for Example {
+    fn day(&self) -> usize {
+        5
+    }
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.len().into())
+    }
+    fn part2(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(0.into())
+    }
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("6"), None)
+    }
+    fn expected_for(&self, name: &str) -> (Option<&'static str>, Option<&'static str>) {
+        match name {
+            "example1" => (Some("3"), Some("0")),
+            _ => self.expected(),
+        }
+    }
+}
+
+const NAMED: [NamedInput; 3] = [
+    NamedInput { day: 5, name: "example1", content: "abc" },
+    NamedInput { day: 5, name: DEFAULT_INPUT, content: "abcdef" },
+    NamedInput { day: 6, name: "example1", content: "x" },
+];
+
+#[test]
+fn named_inputs_source() {
+    const DESC: &str = "Should look up named inputs, listing the default input first";
+    let named = NAMED.to_vec();
+    assert_eq!(named.names(5), vec![DEFAULT_INPUT, "example1"], "{}", DESC);
+    assert_eq!(named.get(5, "example1").as_deref(), Some("abc"), "{}", DESC);
+    assert_eq!(named.get(6, DEFAULT_INPUT), None, "{}", DESC);
+
+    let mut map = HashMap::new();
+    map.insert(5usize, "abcdef");
+    assert_eq!(map.names(5), vec![DEFAULT_INPUT], "{}", DESC);
+    assert_eq!(InputSource::get(&map, 5, "example1"), None, "{}", DESC);
+}
+
+#[test]
+fn expected_answers_per_input() {
+    const DESC: &str = "Should check each input against its own expected answers";
+    let day: Box<dyn AoCDay> = Box::new(Example);
+    let example = solve_day(&day, None, Input::named("example1", "abc"));
+    assert_eq!(example.part(5, 1).unwrap().status, TestStatus::Success, "{}", DESC);
+    assert_eq!(example.part(5, 2).unwrap().status, TestStatus::Success, "{}", DESC);
+    assert_eq!(example.results[0].input, "example1", "{}", DESC);
+
+    let main = solve_day(&day, None, "abcdef");
+    assert_eq!(main.part(5, 1).unwrap().status, TestStatus::Success, "{}", DESC);
+    assert_eq!(main.part(5, 2).unwrap().status, TestStatus::Unknown, "{}", DESC);
+    assert_eq!(main.results[0].input, DEFAULT_INPUT, "{}", DESC);
+}
+
+#[test]
+fn input_hash_ignores_line_endings() {
+    const DESC: &str = "Should hash inputs by content, ignoring line endings and trailing whitespace";
+    let hash = Input::from("1\n2\n3\n").hash();
+    assert_eq!(hash.len(), 16, "{}", DESC);
+    assert_eq!(Input::named("other", "1\r\n2\r\n3").hash(), hash, "{}", DESC);
+    assert_ne!(Input::from("1\n2\n4\n").hash(), hash, "{}", DESC);
+    assert_eq!(Input::from("").hash(), "cbf29ce484222325", "{}", DESC);
+}
@@ -0,0 +1,82 @@
+use aoc_core::{format_solve_time, render_day, render_rankings, Leaderboard};
+use std::time::Duration;
+
+const BOARD: &str = r#"{
+  "owner_id": 1,
+  "event": "2022",
+  "members": {
+    "2": {"id": 2, "name": null, "stars": 1, "local_score": 5, "global_score": 0, "last_star_ts": 1669874525,
+          "completion_day_level": {"1": {"1": {"get_star_ts": 1669874525, "star_index": 30}}}},
+    "3": {"id": 3, "name": "Bob", "stars": 2, "local_score": 20, "global_score": 0, "last_star_ts": 1669960800,
+          "completion_day_level": {"1": {"1": {"get_star_ts": 1669871400, "star_index": 10},
+                                         "2": {"get_star_ts": 1669960800, "star_index": 40}}}},
+    "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 20, "global_score": 0, "last_star_ts": 1669957300,
+          "completion_day_level": {"1": {"1": {"get_star_ts": 1669871554, "star_index": 20},
+                                         "2": {"get_star_ts": 1669872000, "star_index": 25}},
+                                   "2": {"1": {"get_star_ts": 1669957300, "star_index": 35}}}}
+  }
+}"#;
+
+#[test]
+fn leaderboard_parsed() {
+    const DESC: &str = "Should parse a private leaderboard and rank its members";
+    let board = Leaderboard::parse(BOARD).unwrap();
+    assert_eq!(board.event, "2022", "{}", DESC);
+    let ranked: Vec<String> = board.ranked().iter().map(|m| m.display_name()).collect();
+    assert_eq!(ranked, vec!["Alice", "Bob", "(anonymous user #2)"], "{}", DESC);
+    let alice = board.ranked()[0];
+    assert_eq!(alice.star_ts(2, 1), Some(1669957300), "{}", DESC);
+    assert_eq!(alice.part_delta(1), Some(Duration::from_secs(446)), "{}", DESC);
+    assert_eq!(board.solve_time(alice, 1, 1), Some(Duration::from_secs(754)), "{}", DESC);
+    assert!(Leaderboard::parse("{\"event\": \"2022\"}").is_err(), "{}", DESC);
+}
+
+#[test]
+fn leaderboard_rendered() {
+    const DESC: &str = "Should render rankings with stars and per-day solve times";
+    let board = Leaderboard::parse(BOARD).unwrap();
+    let mut rankings = vec![];
+    render_rankings(&board, &mut rankings).unwrap();
+    assert_eq!(
+        String::from_utf8(rankings).unwrap(),
+        "  #  Score  Stars  12  Name
+  1     20      3  *.  Alice
+  2     20      2  *   Bob
+  3      5      1  .   (anonymous user #2)
+",
+        "{}",
+        DESC
+    );
+
+    let late = Leaderboard::parse(
+        r#"{"event": "2022", "members": {"4": {"id": 4, "name": "Dave", "stars": 1, "local_score": 3,
+            "completion_day_level": {"11": {"1": {"get_star_ts": 1670749200, "star_index": 50}}}}}}"#,
+    )
+    .unwrap();
+    let mut rankings = vec![];
+    render_rankings(&late, &mut rankings).unwrap();
+    assert_eq!(
+        String::from_utf8(rankings).unwrap(),
+        "                            11
+  #  Score  Stars  12345678901  Name
+  1      3      1            .  Dave
+",
+        "{}",
+        DESC
+    );
+
+    let mut day = vec![];
+    render_day(&board, 1, &mut day).unwrap();
+    assert_eq!(
+        String::from_utf8(day).unwrap(),
+        "Day 01
+  #        Part 1        Part 2         Delta  Name
+  1       0:12:34       0:20:00       0:07:26  Alice
+  2       0:10:00    1d 1:00:00    1d 0:50:00  Bob
+  3       1:02:05             -             -  (anonymous user #2)
+",
+        "{}",
+        DESC
+    );
+    assert_eq!(format_solve_time(Duration::from_secs(59)), "0:00:59", "{}", DESC);
+}
@@ -0,0 +1,93 @@
+use aoc_core::{solve_days_parallel, Answer, AoCDay, ErrorWrapper, Input, PartResult, Renderer, RunOptions, TestStatus};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+struct Sleepy(usize);
+impl AoCDay for Sleepy {
+    fn day(&self) -> usize {
+        self.0
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        // Later days finish first
+        std::thread::sleep(Duration::from_millis(10 * (5 - self.0 as u64)));
+        Ok(self.0.into())
+    }
+    fn part2(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.into())
+    }
+}
+
+struct Slow;
+impl AoCDay for Slow {
+    fn day(&self) -> usize {
+        9
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        std::thread::sleep(Duration::from_millis(500));
+        Ok("late".into())
+    }
+    fn part2(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok("quick".into())
+    }
+    fn timeout(&self) -> Option<Duration> {
+        Some(Duration::from_millis(50))
+    }
+}
+
+#[derive(Default)]
+struct DayOrder(Vec<usize>);
+impl Renderer for DayOrder {
+    fn day(&mut self, results: &[PartResult]) {
+        assert_eq!(results.len(), 2, "Should pass every part of a day at once");
+        self.0.push(results[0].day);
+    }
+}
+
+#[test]
+fn parallel_preserves_day_order() {
+    const DESC: &str = "Should report days in order regardless of completion order";
+    let days: Vec<Box<dyn AoCDay>> = (1..=4).map(|d| Box::new(Sleepy(d)) as Box<dyn AoCDay>).collect();
+    let work: Vec<(&Box<dyn AoCDay>, Input)> = days.iter().map(|d| (d, Input::from("input"))).collect();
+    for split_parts in [false, true] {
+        let options = RunOptions { jobs: 4, split_parts, ..RunOptions::default() };
+        let mut renderer = DayOrder::default();
+        let report = solve_days_parallel(&work, &options, &mut renderer);
+        assert_eq!(renderer.0, vec![1, 2, 3, 4], "{}", DESC);
+        assert_eq!(report.days(), vec![1, 2, 3, 4], "{}", DESC);
+        let parts: Vec<usize> = report.results.iter().map(|r| r.part).collect();
+        assert_eq!(parts, vec![1, 2, 1, 2, 1, 2, 1, 2], "{}", DESC);
+        assert_eq!(report.part(3, 2).unwrap().answer.as_deref(), Some("input"), "{}", DESC);
+    }
+}
+
+#[test]
+fn parallel_times_out_parts() {
+    const DESC: &str = "Should stop waiting on a part once the day's timeout passes";
+    let days: Vec<Box<dyn AoCDay>> = vec![Box::new(Slow)];
+    let work: Vec<(&Box<dyn AoCDay>, Input)> = days.iter().map(|d| (d, Input::from(""))).collect();
+    let mut renderer = DayOrder::default();
+    let report = solve_days_parallel(&work, &RunOptions::default(), &mut renderer);
+    let part1 = report.part(9, 1).unwrap();
+    assert_eq!(part1.status, TestStatus::TimedOut, "{}", DESC);
+    assert_eq!(part1.duration, Duration::from_millis(50), "{}", DESC);
+    assert_eq!(report.part(9, 2).unwrap().answer.as_deref(), Some("quick"), "{}", DESC);
+}
+
+static STUCK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn parallel_reports_stuck_parts() {
+    const DESC: &str = "Should hand the report to on_stuck while a timed out part is still running, then return it";
+    let days: Vec<Box<dyn AoCDay>> = vec![Box::new(Slow)];
+    let work: Vec<(&Box<dyn AoCDay>, Input)> = days.iter().map(|d| (d, Input::from(""))).collect();
+    let options = RunOptions {
+        on_stuck: Some(|report| {
+            assert_eq!(report.part(9, 1).unwrap().status, TestStatus::TimedOut);
+            STUCK_CALLS.fetch_add(1, Ordering::SeqCst);
+        }),
+        ..RunOptions::default()
+    };
+    let report = solve_days_parallel(&work, &options, &mut DayOrder::default());
+    assert_eq!(STUCK_CALLS.load(Ordering::SeqCst), 1, "{}", DESC);
+    assert_eq!(report.part(9, 1).unwrap().status, TestStatus::TimedOut, "{}", DESC);
+}
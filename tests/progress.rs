@@ -0,0 +1,94 @@
+use aoc_core::{solve_day, Answer, AoCDay, ErrorWrapper, PartProgress, PartResult, ProgressRenderer, Renderer, RunReport, TestStatus, DEFAULT_INPUT};
+
+fn result(day: usize, part: usize, input: &str, status: TestStatus, error: Option<&str>) -> PartResult {
+    PartResult {
+        answer: error.map_or(Some("1".to_string()), |_| None),
+        status,
+        error: error.map(str::to_string),
+        ..PartResult::new(day, part, input)
+    }
+}
+
+fn sample_report() -> RunReport {
+    RunReport {
+        results: vec![
+            result(1, 1, DEFAULT_INPUT, TestStatus::Success, None),
+            PartResult {
+                not_implemented: true,
+                ..result(1, 2, DEFAULT_INPUT, TestStatus::Failure, Some("not yet"))
+            },
+            result(1, 1, "example1", TestStatus::Failure, None),
+            result(2, 1, DEFAULT_INPUT, TestStatus::Rejected, Some("must be lower than 5")),
+            result(2, 2, DEFAULT_INPUT, TestStatus::Unknown, None),
+        ],
+    }
+}
+
+#[test]
+fn day_progress() {
+    const DESC: &str = "Should classify each part of the main input, and days without a solution or input";
+    let report = sample_report();
+    let renderer = ProgressRenderer::new(vec![], vec![1, 2, 3], vec![3]);
+    assert_eq!(
+        renderer.day_progress(&report, 1),
+        [PartProgress::Verified, PartProgress::NotImplemented],
+        "{}",
+        DESC
+    );
+    assert_eq!(
+        renderer.day_progress(&report, 2),
+        [PartProgress::Failing, PartProgress::Unverified],
+        "{}",
+        DESC
+    );
+    assert_eq!(
+        renderer.day_progress(&report, 3),
+        [PartProgress::NoInput, PartProgress::NoInput],
+        "{}",
+        DESC
+    );
+    assert_eq!(
+        renderer.day_progress(&report, 4),
+        [PartProgress::NotImplemented, PartProgress::NotImplemented],
+        "{}",
+        DESC
+    );
+}
+
+#[test]
+fn render_progress() {
+    const DESC: &str = "Should lay the days out as a calendar and count the verified parts";
+    let report = sample_report();
+    let mut out = vec![];
+    let mut renderer = ProgressRenderer::new(&mut out, vec![1, 2, 3], vec![3]);
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[0], " 1 *.    2 x?    3 --    4 ..    5 ..", "{}", DESC);
+    assert_eq!(lines[4], "21 ..   22 ..   23 ..   24 ..   25 ..", "{}", DESC);
+    assert_eq!(lines.last(), Some(&"Verified: 1/50"), "{}", DESC);
+}
+
+struct Half;
+impl AoCDay for Half {
+    fn day(&self) -> usize {
+        4
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Err(ErrorWrapper::Simple("NotImplemented".to_string()))
+    }
+}
+
+#[test]
+fn progress_not_implemented() {
+    const DESC: &str = "Should only count parts failing with ErrorWrapper::NotImplemented as not implemented";
+    let day: Box<dyn AoCDay> = Box::new(Half);
+    let report = solve_day(&day, None, "input");
+    let part2 = report.part(4, 2).unwrap();
+    assert!(part2.not_implemented, "{}", DESC);
+    assert_eq!(PartProgress::from_result(part2), PartProgress::NotImplemented, "{}", DESC);
+    let part1 = report.part(4, 1).unwrap();
+    assert!(!part1.not_implemented, "{}", DESC);
+    assert_eq!(PartProgress::from_result(part1), PartProgress::Failing, "{}", DESC);
+}
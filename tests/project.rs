@@ -0,0 +1,50 @@
+use aoc_core::{Answer, AoCDay, AoCProject, ErrorWrapper, ExitStatus};
+use std::collections::HashMap;
+
+struct Length;
+impl AoCDay for Length {
+    fn day(&self) -> usize {
+        1
+    }
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.trim().len().into())
+    }
+    fn parts(&self) -> usize {
+        1
+    }
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("3"), None)
+    }
+}
+
+fn execute(args: &[&str]) -> ExitStatus {
+    let mut answers = std::env::temp_dir();
+    answers.push(format!("aoc_core_project_{}", std::process::id()));
+    let project = AoCProject::new(2015, "0.1".to_string(), None, "me".to_string(), None).with_answers_dir(answers);
+    let days: Vec<Box<dyn AoCDay>> = vec![Box::new(Length)];
+    let inputs: HashMap<usize, &str> = HashMap::from([(1, "abc")]);
+    project.execute_from(std::iter::once("aoc").chain(args.iter().copied()), &days, &inputs)
+}
+
+#[test]
+fn run_stdin_needs_single_day() {
+    const DESC: &str = "Should refuse to read input from stdin for more than one day";
+    assert_eq!(execute(&["run", "--day", "1-3", "--stdin"]), ExitStatus::Usage, "{}", DESC);
+    assert_eq!(execute(&["run", "--day", "1,2", "--input-file", "-"]), ExitStatus::Usage, "{}", DESC);
+    assert_eq!(execute(&["run", "--stdin"]), ExitStatus::Usage, "{}", DESC);
+}
+
+#[test]
+fn run_input_file() {
+    const DESC: &str = "Should run a single day against the input file given";
+    let mut path = std::env::temp_dir();
+    path.push(format!("aoc_core_project_input_{}.txt", std::process::id()));
+    let file = path.to_str().unwrap();
+
+    std::fs::write(&path, "xyz\n").unwrap();
+    assert_eq!(execute(&["run", "--day", "1", "--input-file", file]), ExitStatus::Passed, "{}", DESC);
+    std::fs::write(&path, "wxyz\n").unwrap();
+    assert_eq!(execute(&["run", "--day", "1", "-f", file]), ExitStatus::WrongAnswer, "{}", DESC);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(execute(&["run", "--day", "1", "-f", file]), ExitStatus::Missing, "{}", DESC);
+}
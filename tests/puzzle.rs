@@ -0,0 +1,71 @@
+use aoc_core::{decode_entities, extract_examples, puzzle_markdown};
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.</p>
+<p>For example, suppose the Elves finish writing their items' <em>Calories</em> and end up with the following list:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<p>This list represents the Calories of the food carried by <a href="https://example.com/elves">two Elves</a>:</p>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories, a total of <code><em>3000</em></code> Calories.</li>
+<li>The second Elf is carrying one food item with <code>4000</code> Calories.</li>
+</ul>
+<p>Find the Elf carrying the most Calories. <em>How many total Calories is that Elf carrying?</em></p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Calories &amp; snacks:</p>
+<pre><code>a &lt;b&gt; <em>c</em>
+</code></pre>
+</article>
+</main>
+</body>
+</html>"#;
+
+const MARKDOWN: &str = "## --- Day 1: Calorie Counting ---
+
+The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.
+
+For example, suppose the Elves finish writing their items' *Calories* and end up with the following list:
+
+```
+1000
+2000
+
+4000
+```
+
+This list represents the Calories of the food carried by [two Elves](https://example.com/elves):
+
+- The first Elf is carrying food with `1000` and `2000` Calories, a total of `3000` Calories.
+- The second Elf is carrying one food item with `4000` Calories.
+
+Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*
+
+## --- Part Two ---
+
+Calories & snacks:
+
+```
+a <b> c
+```
+";
+
+#[test]
+fn puzzle_converted_to_markdown() {
+    const DESC: &str = "Should convert the puzzle articles of a page to Markdown";
+    assert_eq!(puzzle_markdown(PAGE), MARKDOWN, "{}", DESC);
+}
+
+#[test]
+fn examples_extracted() {
+    const DESC: &str = "Should extract code blocks from a puzzle page, without markup";
+    assert_eq!(extract_examples(PAGE), vec!["1000\n2000\n\n4000\n", "a <b> c\n"], "{}", DESC);
+    assert_eq!(decode_entities("&lt;&#65;&#x42;&gt; &amp;amp; & x"), "<AB> &amp; & x", "{}", DESC);
+}
@@ -0,0 +1,109 @@
+use aoc_core::{part_from_json, CsvRenderer, JsonRenderer, PartResult, Renderer, RunReport, SummaryRenderer, TestStatus, TextRenderer};
+use std::time::Duration;
+
+fn sample_report() -> RunReport {
+    RunReport {
+        results: vec![
+            PartResult {
+                answer: Some("a,\"b\"".to_string()),
+                expected: Some("42".to_string()),
+                status: TestStatus::Failure,
+                duration: Duration::from_nanos(1500),
+                ..PartResult::new(1, 1, "default")
+            },
+            PartResult {
+                status: TestStatus::Failure,
+                duration: Duration::from_nanos(20),
+                error: Some("NotImplemented".to_string()),
+                not_implemented: true,
+                ..PartResult::new(1, 2, "example1")
+            },
+        ],
+    }
+}
+
+#[test]
+fn render_csv() {
+    const DESC: &str = "Should write a header, parse times and quote fields containing delimiters";
+    let mut report = sample_report();
+    report.results[0].parse_duration = Some(Duration::from_nanos(300));
+    report.results[1].continued = true;
+    let mut out = vec![];
+    let mut renderer = CsvRenderer::new(&mut out);
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "day,part,input,status,answer,expected,duration_ns,parse_ns,continued,error\n\
+         1,1,default,Failure,\"a,\"\"b\"\"\",42,1500,300,false,\n\
+         1,2,example1,Failure,,,20,,true,NotImplemented\n",
+        "{}",
+        DESC
+    );
+}
+
+#[test]
+fn render_json() {
+    const DESC: &str = "Should write one object per part with status names";
+    let report = sample_report();
+    let mut out = vec![];
+    let mut renderer = JsonRenderer::new(&mut out);
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(value[0]["answer"], "a,\"b\"", "{}", DESC);
+    assert_eq!(value[0]["status"], "Failure", "{}", DESC);
+    assert_eq!(value[0]["duration_ns"], 1500, "{}", DESC);
+    assert!(value[1]["answer"].is_null(), "{}", DESC);
+    assert_eq!(value[1]["error"], "NotImplemented", "{}", DESC);
+    assert_eq!(value[1]["input"], "example1", "{}", DESC);
+    let part2 = part_from_json(&value[1]).unwrap();
+    assert!(part2.not_implemented, "{}", DESC);
+    assert!(!part_from_json(&value[0]).unwrap().not_implemented, "{}", DESC);
+}
+
+#[test]
+fn render_summary() {
+    const DESC: &str = "Should write a row per day and detail mismatched answers";
+    let report = sample_report();
+    let mut out = vec![];
+    let mut renderer = SummaryRenderer::new(&mut out);
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[0], "Day  Part 1  Part 2       Time", "{}", DESC);
+    assert_eq!(lines[1], " 01       ✗       ✗     1.52µs", "{}", DESC);
+    assert!(out.contains("Day 01, Part 1: expected 42, got a,\"b\""), "{}", DESC);
+    assert!(out.contains("Passed: 0, Failed: 1, Unverified: 1"), "{}", DESC);
+
+    let bonus = [
+        PartResult::new(25, 1, "default"),
+        PartResult {
+            status: TestStatus::Success,
+            expected: Some("done".to_string()),
+            ..PartResult::new(25, 3, "default")
+        },
+    ];
+    let mut out = vec![];
+    SummaryRenderer::new(&mut out).day(&bonus);
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.lines().nth(1), Some(" 25       ?       -        0ns  Part 3: ✓"), "{}", DESC);
+}
+
+#[test]
+fn render_text_art() {
+    const DESC: &str = "Should put multi-line answers on lines of their own";
+    let mut report = sample_report();
+    report.results[0].answer = Some("#..#\n####\n".to_string());
+    let mut out = vec![];
+    let mut renderer = TextRenderer::new(&mut out);
+    renderer.day(&report.results);
+    renderer.finish(&report);
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[0], "Day 01, Part 1: ✗ (1.50µs)", "{}", DESC);
+    assert_eq!(lines[1], "        #..#", "{}", DESC);
+    assert_eq!(lines[2], "        ####", "{}", DESC);
+    assert_eq!(lines[3], "        Part 2: ✗ NotImplemented (20ns)", "{}", DESC);
+}
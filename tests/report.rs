@@ -0,0 +1,238 @@
+use aoc_core::{run_part, solve_day, Answer, AoCDay, ErrorWrapper, ParsedDay, Stateful, StatefulDay, TestStatus};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct Example;
+impl AoCDay for Example {
+    fn day(&self) -> usize {
+        7
+    }
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.trim().len().into())
+    }
+    fn part2(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Err(ErrorWrapper::NotImplemented)
+    }
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("5"), None)
+    }
+}
+
+#[test]
+fn solve_day_both_parts() {
+    const DESC: &str = "Should collect a result for each part without printing";
+    let day: Box<dyn AoCDay> = Box::new(Example);
+    let report = solve_day(&day, None, "hello");
+    assert_eq!(report.results.len(), 2, "{}", DESC);
+
+    let part1 = report.part(7, 1).unwrap();
+    assert_eq!(part1.status, TestStatus::Success, "{}", DESC);
+    assert_eq!(part1.answer.as_deref(), Some("5"), "{}", DESC);
+    assert_eq!(part1.expected.as_deref(), Some("5"), "{}", DESC);
+    assert!(part1.error.is_none(), "{}", DESC);
+
+    let part2 = report.part(7, 2).unwrap();
+    assert_eq!(part2.status, TestStatus::Failure, "{}", DESC);
+    assert!(part2.answer.is_none(), "{}", DESC);
+    assert_eq!(part2.error.as_deref(), Some("NotImplemented"), "{}", DESC);
+}
+
+#[test]
+fn solve_day_single_part() {
+    const DESC: &str = "Should only run the selected part";
+    let day: Box<dyn AoCDay> = Box::new(Example);
+    let report = solve_day(&day, Some(1), "hi");
+    assert_eq!(report.results.len(), 1, "{}", DESC);
+    assert_eq!(report.results[0].status, TestStatus::Failure, "{}", DESC);
+    assert_eq!(report.days(), vec![7], "{}", DESC);
+}
+
+struct Panicky;
+impl AoCDay for Panicky {
+    fn day(&self) -> usize {
+        3
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        panic!("boom {}", 3)
+    }
+    fn part2(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok("fine".into())
+    }
+}
+
+#[test]
+fn solve_day_isolates_panics() {
+    const DESC: &str = "Should report a panicking part and keep running the rest";
+    let day: Box<dyn AoCDay> = Box::new(Panicky);
+    let report = solve_day(&day, None, "");
+
+    let part1 = report.part(3, 1).unwrap();
+    assert_eq!(part1.status, TestStatus::Panicked, "{}", DESC);
+    let error = part1.error.as_deref().unwrap();
+    assert!(error.contains("boom 3"), "{}: {}", DESC, error);
+    assert!(error.contains("tests/report.rs"), "{}: {}", DESC, error);
+
+    assert_eq!(report.part(3, 2).unwrap().answer.as_deref(), Some("fine"), "{}", DESC);
+}
+
+struct Numbers {
+    parses: Arc<AtomicUsize>,
+}
+impl ParsedDay for Numbers {
+    type Parsed = Vec<i64>;
+
+    fn day(&self) -> usize {
+        9
+    }
+    fn parse(&self, input: &str) -> Result<Vec<i64>, ErrorWrapper> {
+        self.parses.fetch_add(1, Ordering::SeqCst);
+        Ok(input.split_whitespace().map(str::parse).collect::<Result<Vec<i64>, _>>()?)
+    }
+    fn part1(&self, parsed: &Vec<i64>) -> Result<Answer, ErrorWrapper> {
+        Ok(parsed.iter().sum::<i64>().into())
+    }
+    fn part2(&self, parsed: &Vec<i64>) -> Result<Answer, ErrorWrapper> {
+        Ok(parsed.iter().product::<i64>().into())
+    }
+    fn expected(&self) -> (Option<&'static str>, Option<&'static str>) {
+        (Some("6"), None)
+    }
+}
+
+#[test]
+fn solve_day_parses_once() {
+    const DESC: &str = "Should parse the input once for both parts and time it separately";
+    let parses = Arc::new(AtomicUsize::new(0));
+    let day: Box<dyn AoCDay> = Box::new(Numbers { parses: parses.clone() });
+    let report = solve_day(&day, None, "1 2 3");
+    assert_eq!(parses.load(Ordering::SeqCst), 1, "{}", DESC);
+
+    let part1 = report.part(9, 1).unwrap();
+    assert_eq!(part1.status, TestStatus::Success, "{}", DESC);
+    assert!(part1.parse_duration.is_some(), "{}", DESC);
+    let part2 = report.part(9, 2).unwrap();
+    assert_eq!(part2.answer.as_deref(), Some("6"), "{}", DESC);
+    assert!(part2.parse_duration.is_none(), "{}", DESC);
+
+    assert_eq!(day.part2("2 5").unwrap(), Answer::Integer(10), "{}", DESC);
+}
+
+#[test]
+fn solve_day_parse_error() {
+    const DESC: &str = "Should fail every part with the parse error";
+    let day: Box<dyn AoCDay> = Box::new(Numbers { parses: Arc::default() });
+    let report = solve_day(&day, None, "1 two 3");
+    assert_eq!(report.results.len(), 2, "{}", DESC);
+    for r in report.results.iter() {
+        assert_eq!(r.status, TestStatus::Failure, "{}", DESC);
+        assert!(r.error.as_deref().unwrap().contains("ParseError"), "{}", DESC);
+    }
+    assert_eq!(report.part(9, 1).unwrap().expected.as_deref(), Some("6"), "{}", DESC);
+}
+
+struct Walk {
+    steps: Arc<AtomicUsize>,
+}
+impl StatefulDay for Walk {
+    // Position after every step
+    type State = Vec<i64>;
+
+    fn day(&self) -> usize {
+        11
+    }
+    fn part1(&self, input: &str) -> Result<(Answer, Vec<i64>), ErrorWrapper> {
+        let mut positions = vec![0];
+        for step in input.split_whitespace() {
+            self.steps.fetch_add(1, Ordering::SeqCst);
+            positions.push(positions[positions.len() - 1] + step.parse::<i64>()?);
+        }
+        Ok((positions[positions.len() - 1].into(), positions))
+    }
+    fn part2(&self, _input: &str, positions: Vec<i64>) -> Result<Answer, ErrorWrapper> {
+        Ok((*positions.iter().max().unwrap()).into())
+    }
+}
+
+#[test]
+fn solve_day_continues_part1() {
+    const DESC: &str = "Should pass the state part 1 left on to part 2 instead of starting over";
+    let steps = Arc::new(AtomicUsize::new(0));
+    let day: Box<dyn AoCDay> = Box::new(Stateful(Walk { steps: steps.clone() }));
+    let report = solve_day(&day, None, "3 4 -5");
+    assert_eq!(steps.load(Ordering::SeqCst), 3, "{}", DESC);
+    assert_eq!(report.part(11, 1).unwrap().answer.as_deref(), Some("2"), "{}", DESC);
+    let part2 = report.part(11, 2).unwrap();
+    assert_eq!(part2.answer.as_deref(), Some("7"), "{}", DESC);
+    assert!(part2.continued, "{}", DESC);
+    assert!(report.results.iter().all(|r| r.parse_duration.is_none()), "{}", DESC);
+}
+
+#[test]
+fn run_part_runs_part1_first() {
+    const DESC: &str = "Should run part 1 for its state when part 2 is run on its own";
+    let steps = Arc::new(AtomicUsize::new(0));
+    let day: Box<dyn AoCDay> = Box::new(Stateful(Walk { steps: steps.clone() }));
+    let part2 = run_part(&day, 2, "3 4 -5");
+    assert_eq!(part2.answer.as_deref(), Some("7"), "{}", DESC);
+    assert!(!part2.continued, "{}", DESC);
+    assert_eq!(steps.load(Ordering::SeqCst), 3, "{}", DESC);
+}
+
+struct Finale;
+impl AoCDay for Finale {
+    fn day(&self) -> usize {
+        25
+    }
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.len().into())
+    }
+    fn parts(&self) -> usize {
+        1
+    }
+}
+
+#[test]
+fn solve_day_single_part_day() {
+    const DESC: &str = "Should only run the parts a day has";
+    let day: Box<dyn AoCDay> = Box::new(Finale);
+    let report = solve_day(&day, None, "abc");
+    assert_eq!(report.results.len(), 1, "{}", DESC);
+    assert_eq!(report.results[0].answer.as_deref(), Some("3"), "{}", DESC);
+}
+
+struct Bonus;
+impl AoCDay for Bonus {
+    fn day(&self) -> usize {
+        4
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(1.into())
+    }
+    fn part2(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(2.into())
+    }
+    fn parts(&self) -> usize {
+        3
+    }
+    fn part(&self, part: usize, input: &str) -> Result<Answer, ErrorWrapper> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            3 => Ok(Answer::Art(format!("{}\n{}", input, input))),
+            _ => Err(ErrorWrapper::Simple(format!("Invalid part {}", part))),
+        }
+    }
+}
+
+#[test]
+fn solve_day_extra_parts() {
+    const DESC: &str = "Should run extra parts along with the usual two, or on their own";
+    let day: Box<dyn AoCDay> = Box::new(Bonus);
+    let report = solve_day(&day, None, "#");
+    assert_eq!(report.results.len(), 3, "{}", DESC);
+    assert_eq!(report.part(4, 3).unwrap().answer.as_deref(), Some("#\n#"), "{}", DESC);
+
+    let part3 = run_part(&day, 3, "#");
+    assert_eq!(part3.status, TestStatus::Unknown, "{}", DESC);
+    assert_eq!(run_part(&day, 4, "#").status, TestStatus::Failure, "{}", DESC);
+}
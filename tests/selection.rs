@@ -0,0 +1,32 @@
+use aoc_core::DaySelection;
+
+#[test]
+fn selection_lists_and_ranges() {
+    const DESC: &str = "Should select listed days and days within ranges";
+    let selection: DaySelection = "1-5,9,12-".parse().unwrap();
+    let days: Vec<usize> = (1..=25).filter(|d| selection.contains(*d)).collect();
+    let mut expected: Vec<usize> = (1..=5).collect();
+    expected.push(9);
+    expected.extend(12..=25);
+    assert_eq!(days, expected, "{}", DESC);
+    assert_eq!(selection.single(), None, "{}", DESC);
+    assert_eq!(selection.explicit_days(), vec![9], "{}", DESC);
+}
+
+#[test]
+fn selection_single() {
+    const DESC: &str = "Should recognize a selection of exactly one day";
+    let selection: DaySelection = "7".parse().unwrap();
+    assert_eq!(selection.single(), Some(7), "{}", DESC);
+    assert!(selection.contains(7) && !selection.contains(8), "{}", DESC);
+    let open_start: DaySelection = "-3".parse().unwrap();
+    assert!(open_start.contains(1) && !open_start.contains(4), "{}", DESC);
+}
+
+#[test]
+fn selection_invalid() {
+    const DESC: &str = "Should reject malformed selections";
+    for input in ["", "1,,2", "a", "5-3", "1-2-3"] {
+        assert!(input.parse::<DaySelection>().is_err(), "{}: {:?}", DESC, input);
+    }
+}
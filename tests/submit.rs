@@ -0,0 +1,91 @@
+#![cfg(feature = "net")]
+use aoc_core::{parse_submit_response, AoCClient, SubmitOutcome, Verdict};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+fn article(text: &str) -> String {
+    format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text)
+}
+
+#[test]
+fn submit_responses_parsed() {
+    const DESC: &str = "Should recognize each kind of response to a submitted answer";
+    let cases = [
+        (
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving Christmas.",
+            SubmitOutcome::Verdict(Verdict::Correct),
+        ),
+        (
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2022/day/1\">Return to Day 1</a>]",
+            SubmitOutcome::Verdict(Verdict::TooHigh),
+        ),
+        (
+            "That's not the right answer; your answer is too low.",
+            SubmitOutcome::Verdict(Verdict::TooLow),
+        ),
+        (
+            "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+            SubmitOutcome::Verdict(Verdict::Wrong),
+        ),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait. [<a href=\"/2022/day/1\">Return to Day 1</a>]",
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(94))),
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2022/day/1\">Return to Day 1</a>]",
+            SubmitOutcome::WrongLevel,
+        ),
+        ("Something else entirely.", SubmitOutcome::Unrecognized("Something else entirely.".to_string())),
+    ];
+    for (text, outcome) in cases {
+        assert_eq!(parse_submit_response(&article(text)), outcome, "{}", DESC);
+    }
+}
+
+#[test]
+fn answer_submitted() {
+    const DESC: &str = "Should post the answer to the puzzle site with the session token";
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut head = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(l) = line.to_ascii_lowercase().strip_prefix("content-length: ") {
+                length = l.trim().parse().unwrap();
+            }
+            head.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        let page = article("That's not the right answer; your answer is too low.");
+        write!(
+            reader.get_mut(),
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            page.len(),
+            page
+        )
+        .unwrap();
+        (head, String::from_utf8(body).unwrap())
+    });
+
+    let client = AoCClient {
+        base_url: url,
+        session: Some("abc123".to_string()),
+        ..AoCClient::new(2015, "tests".to_string())
+    };
+    let outcome = client.submit(4, 2, "1 2").unwrap();
+    assert_eq!(outcome, SubmitOutcome::Verdict(Verdict::TooLow), "{}", DESC);
+    let (head, body) = server.join().unwrap();
+    assert!(head.starts_with("POST /2015/day/4/answer HTTP/1.1\r\n"), "{}", DESC);
+    assert!(head.contains("Cookie: session=abc123\r\n"), "{}", DESC);
+    assert_eq!(body, "level=2&answer=1+2", "{}", DESC);
+}
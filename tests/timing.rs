@@ -0,0 +1,47 @@
+use aoc_core::{format_duration, solve_day, Answer, AoCDay, DayTimings, ErrorWrapper};
+use std::time::Duration;
+
+struct Day;
+
+impl AoCDay for Day {
+    fn day(&self) -> usize {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ErrorWrapper> {
+        Ok(input.len().into())
+    }
+}
+
+#[test]
+fn format_duration_units() {
+    const DESC: &str = "Should pick the largest unit that keeps the value above 1";
+    assert_eq!(format_duration(Duration::from_nanos(512)), "512ns", "{}", DESC);
+    assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.34µs", "{}", DESC);
+    assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms", "{}", DESC);
+    assert_eq!(format_duration(Duration::from_millis(2_030)), "2.03s", "{}", DESC);
+}
+
+#[test]
+fn day_timings_total() {
+    const DESC: &str = "Should sum the durations of every recorded part";
+    let mut timings = DayTimings::new(1);
+    timings.parts.push((1, Duration::from_millis(3)));
+    timings.parts.push((2, Duration::from_millis(4)));
+    assert_eq!(timings.total(), Duration::from_millis(7), "{}", DESC);
+    assert_eq!(timings.part(2), Some(Duration::from_millis(4)), "{}", DESC);
+    assert_eq!(timings.part(3), None, "{}", DESC);
+}
+
+#[test]
+fn report_timings() {
+    const DESC: &str = "Should collect the time of each part of a day from a report";
+    let day: Box<dyn AoCDay> = Box::new(Day);
+    let report = solve_day(&day, None, "abc");
+    let timings = report.timings(1);
+    assert_eq!(timings.day, 1, "{}", DESC);
+    assert_eq!(timings.parts.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![1, 2], "{}", DESC);
+    assert_eq!(timings.part(1), Some(report.results[0].elapsed()), "{}", DESC);
+    assert_eq!(timings.total(), report.day_duration(1), "{}", DESC);
+    assert!(report.timings(2).parts.is_empty(), "{}", DESC);
+}
@@ -0,0 +1,88 @@
+use aoc_core::{latest_modified, watch_day, Answer, AoCDay, ErrorWrapper, RunOptions, WatchOptions};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+struct Stuck;
+impl AoCDay for Stuck {
+    fn day(&self) -> usize {
+        1
+    }
+    fn part1(&self, _input: &str) -> Result<Answer, ErrorWrapper> {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(100));
+        Ok(1.into())
+    }
+    fn parts(&self) -> usize {
+        1
+    }
+    fn timeout(&self) -> Option<Duration> {
+        Some(Duration::from_millis(10))
+    }
+}
+
+#[test]
+fn latest_modified_filters_extension() {
+    const DESC: &str = "Should find the newest matching file beneath a directory";
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("aoc_core_watch_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+
+    let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    let new = SystemTime::UNIX_EPOCH + Duration::from_secs(2_000_000);
+    let newest = SystemTime::UNIX_EPOCH + Duration::from_secs(3_000_000);
+    for (name, time) in [("a.rs", old), ("nested/b.rs", new), ("nested/input.txt", newest)] {
+        let file = std::fs::File::create(dir.join(name)).unwrap();
+        file.set_modified(time).unwrap();
+    }
+
+    assert_eq!(latest_modified(&dir, Some("rs")), Some(new), "{}", DESC);
+    assert_eq!(latest_modified(&dir.join("a.rs"), None), Some(old), "{}", DESC);
+    assert_eq!(latest_modified(&dir.join("nested/input.txt"), Some("rs")), None, "{}", DESC);
+    assert!(latest_modified(&dir.join("missing"), None).is_none(), "{}", DESC);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_outlives_timed_out_parts() {
+    const DESC: &str = "Should keep watching after a part times out instead of handing the run to on_stuck";
+    let mut input = std::env::temp_dir();
+    input.push(format!("aoc_core_watch_stuck_{}.txt", std::process::id()));
+    let file = std::fs::File::create(&input).unwrap();
+    file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000)).unwrap();
+
+    let watch = WatchOptions {
+        input: input.clone(),
+        sources: None,
+        interval: Duration::from_millis(10),
+    };
+    let options = RunOptions {
+        on_stuck: Some(|_| panic!("on_stuck called while watching")),
+        ..RunOptions::default()
+    };
+    let watcher = std::thread::spawn(move || {
+        let day: Box<dyn AoCDay> = Box::new(Stuck);
+        watch_day(&day, &options, &watch)
+    });
+
+    let wait_for_runs = |runs: usize| {
+        let start = Instant::now();
+        while RUNS.load(Ordering::SeqCst) < runs && start.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    };
+    wait_for_runs(1);
+    std::fs::File::options()
+        .write(true)
+        .open(&input)
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(2_000_000))
+        .unwrap();
+    wait_for_runs(2);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2, "{}", DESC);
+    assert!(!watcher.is_finished(), "{}", DESC);
+
+    std::fs::remove_file(&input).unwrap();
+}